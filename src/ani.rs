@@ -0,0 +1,155 @@
+// The UQM .ani format, one frame to a line:
+//
+//   <image> <transparent colour> <colour map> <hotspot x> <hotspot y>
+//
+// with the image named relative to the .ani file. The colour columns must be
+// numbers but are otherwise ignored, as the images carry their own
+// transparency. Blank lines are skipped.
+use ggez::{Context, GameError};
+use std::fmt;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub file: String,
+    pub hotspotx: f32,
+    pub hotspoty: f32,
+}
+
+#[derive(Debug)]
+pub enum AniErrorKind {
+    Open(GameError),
+    Read(std::io::Error),
+    Missing(&'static str),
+    Invalid {column: &'static str, value: String},
+    Extra(String),
+    Image {file: PathBuf, error: GameError},
+    Length {expected: usize, found: usize},
+}
+
+#[derive(Debug)]
+pub struct AniError {
+    pub file: PathBuf,
+    pub line: Option<usize>,// counting from 1
+    pub kind: AniErrorKind,
+}
+
+impl fmt::Display for AniError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+	match self.line {
+	    Some(line) => write!(f, "{}:{}: ", self.file.display(), line)?,
+	    None => write!(f, "{}: ", self.file.display())?,
+	}
+	match &self.kind {
+	    AniErrorKind::Open(error) => write!(f, "cannot open: {error}"),
+	    AniErrorKind::Read(error) => write!(f, "cannot read: {error}"),
+	    AniErrorKind::Missing(column) => write!(f, "missing {column}"),
+	    AniErrorKind::Invalid {column, value} => write!(f, "{column} {value:?} is not a number"),
+	    AniErrorKind::Extra(value) => write!(f, "unexpected {value:?} after the hotspot"),
+	    AniErrorKind::Image {file, error} => write!(f, "image {}: {error}", file.display()),
+	    AniErrorKind::Length {expected, found} => write!(f, "{found} frames where {expected} are needed"),
+	}
+    }
+}
+
+impl std::error::Error for AniError {}
+
+impl AniError {
+    pub fn new(file: &Path, line: Option<usize>, kind: AniErrorKind) -> Self {
+	AniError {
+	    file: file.to_owned(),
+	    line,
+	    kind,
+	}
+    }
+}
+
+// None for a blank line
+pub fn parse_line(line: &str) -> Result<Option<Frame>, AniErrorKind> {
+    let mut columns = line.split_whitespace();
+    let Some(file) = columns.next() else {
+	return Ok(None);
+    };
+    let mut next = |column| columns.next().ok_or(AniErrorKind::Missing(column));
+    let transparent = next("transparent colour")?;
+    let colormap = next("colour map")?;
+    let hotspotx = next("hotspot x")?;
+    let hotspoty = next("hotspot y")?;
+    if let Some(extra) = columns.next() {
+	return Err(AniErrorKind::Extra(extra.to_owned()));
+    }
+
+    fn number<T: std::str::FromStr>(column: &'static str, value: &str) -> Result<T, AniErrorKind> {
+	value.parse().map_err(|_| AniErrorKind::Invalid {column, value: value.to_owned()})
+    }
+    number::<i32>("transparent colour", transparent)?;
+    number::<i32>("colour map", colormap)?;
+    Ok(Some(Frame {
+	file: file.to_owned(),
+	hotspotx: number("hotspot x", hotspotx)?,
+	hotspoty: number("hotspot y", hotspoty)?,
+    }))
+}
+
+// every frame with the line it came from
+pub fn read(ctx: &Context, src: &Path) -> Result<Vec<(usize, Frame)>, AniError> {
+    let file = ctx.fs.open(src).map_err(|error| AniError::new(src, None, AniErrorKind::Open(error)))?;
+    let mut frames = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+	let number = index + 1;
+	let line = line.map_err(|error| AniError::new(src, Some(number), AniErrorKind::Read(error)))?;
+	if let Some(frame) = parse_line(&line).map_err(|kind| AniError::new(src, Some(number), kind))? {
+	    frames.push((number, frame));
+	}
+    }
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_a_frame() {
+	let frame = parse_line("  ship-000.png -1 0 12 -8.5 ").unwrap().unwrap();
+	assert_eq!(frame.file, "ship-000.png");
+	assert_eq!(frame.hotspotx, 12.0);
+	assert_eq!(frame.hotspoty, -8.5);
+    }
+
+    #[test]
+    fn skips_blank_lines() {
+	assert!(parse_line("").unwrap().is_none());
+	assert!(parse_line(" \t ").unwrap().is_none());
+    }
+
+    #[test]
+    fn rejects_missing_columns() {
+	for (line, column) in [
+	    ("ship.png", "transparent colour"),
+	    ("ship.png 0", "colour map"),
+	    ("ship.png 0 0", "hotspot x"),
+	    ("ship.png 0 0 1", "hotspot y"),
+	] {
+	    assert!(matches!(parse_line(line), Err(AniErrorKind::Missing(missing)) if missing == column), "{line}");
+	}
+    }
+
+    #[test]
+    fn rejects_columns_that_are_not_numbers() {
+	for (line, column) in [
+	    ("ship.png x 0 1 1", "transparent colour"),
+	    ("ship.png 0 1.5 1 1", "colour map"),
+	    ("ship.png 0 0 left 1", "hotspot x"),
+	    ("ship.png 0 0 1 -", "hotspot y"),
+	] {
+	    assert!(matches!(parse_line(line), Err(AniErrorKind::Invalid {column: invalid, ..}) if invalid == column), "{line}");
+	}
+    }
+
+    #[test]
+    fn rejects_extra_columns() {
+	assert!(matches!(parse_line("ship.png 0 0 1 1 2"), Err(AniErrorKind::Extra(extra)) if extra == "2"));
+    }
+}
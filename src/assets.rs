@@ -0,0 +1,208 @@
+use ggez::Context;
+use ggez::graphics;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::{Duration, Instant, SystemTime};
+
+// the folder mounted by ggez, watched for changes
+const ROOT: &str = "./resources";
+
+thread_local! {
+    static IMAGES: RefCell<HashMap<PathBuf, SharedImage>> = RefCell::new(HashMap::new());
+    static GENERATION: Cell<u64> = const { Cell::new(0) };
+    static ANIMATIONS: RefCell<HashMap<PathBuf, Loaded>> = RefCell::new(HashMap::new());
+    static PROBLEMS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+// for something missing or broken that the game carries on without
+pub fn problem(message: impl std::fmt::Display) {
+    let message = message.to_string();
+    eprintln!("{message}");
+    PROBLEMS.with_borrow_mut(|problems| problems.push(message));
+}
+
+// the problems since this was last called, for showing on screen
+pub fn take_problems() -> Vec<String> {
+    PROBLEMS.take()
+}
+
+// stands in for an image that could not be loaded
+pub fn placeholder(ctx: &mut Context) -> graphics::Image {
+    graphics::Image::from_color(ctx, 32, 32, Some(graphics::Color::MAGENTA))
+}
+
+// every holder sees the new image once the file is reloaded
+#[derive(Debug, Clone)]
+pub struct SharedImage(Rc<RefCell<graphics::Image>>);
+
+impl SharedImage {
+    pub fn load(ctx: &mut Context, src: impl AsRef<Path>) -> SharedImage {
+	let src = src.as_ref();
+	if let Some(image) = IMAGES.with_borrow(|images| images.get(src).cloned()) {
+	    return image;
+	}
+	// kept under its path, so fixing the file replaces the placeholder
+	let image = graphics::Image::from_path(ctx, src).unwrap_or_else(|error| {
+	    problem(format!("{}: {}", src.display(), error));
+	    placeholder(ctx)
+	});
+	let image = SharedImage(Rc::new(RefCell::new(image)));
+	IMAGES.with_borrow_mut(|images| images.insert(src.to_owned(), image.clone()));
+	image
+    }
+
+    pub fn get(&self) -> graphics::Image {
+	self.0.borrow().clone()
+    }
+}
+
+// animations and scripts are reread by their holders when this changes
+pub fn generation() -> u64 {
+    GENERATION.get()
+}
+
+// an animation and the generation it was last read in
+type Loaded = (u64, Option<Rc<crate::AnimationVar>>);
+
+// an animation shared by everything playing it, reread each generation
+// a broken one is reported once, and keeps what was last read of it if anything
+pub fn animation(ctx: &mut Context, src: impl AsRef<Path>) -> Option<Rc<crate::AnimationVar>> {
+    let src = src.as_ref();
+    let generation = GENERATION.get();
+    let previous = match ANIMATIONS.with_borrow(|animations| animations.get(src).cloned()) {
+	Some((read, animation)) if read == generation => return animation,
+	Some((_, animation)) => animation,
+	None => None,
+    };
+    let animation = crate::AnimationVar::try_new(ctx, src).map(Rc::new).map_err(problem).ok().or(previous);
+    ANIMATIONS.with_borrow_mut(|animations| animations.insert(src.to_owned(), (generation, animation.clone())));
+    animation
+}
+
+// reapplies changed files, returning what could not be
+pub fn reload(ctx: &mut Context, changed: &[PathBuf]) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut animations = false;
+    let mut specs = Vec::new();
+    let mut scripts = false;
+
+    for src in changed {
+	match src.extension().and_then(|extension| extension.to_str()) {
+	    Some("ani") => {
+		animations = true;
+		// one shared through animation() is reported when it is next read
+		if ANIMATIONS.with_borrow(|animations| !animations.contains_key(src)) {
+		    if let Err(error) = crate::AnimationVar::try_new(ctx, src) {
+			errors.push(format!("{}: {}", src.display(), error));
+		    }
+		}
+	    },
+	    Some("toml") if src.starts_with("/specs") => specs.push(src.clone()),
+	    Some("rhai") => scripts = true,
+	    _ => {
+		let Some(image) = IMAGES.with_borrow(|images| images.get(src).cloned()) else {
+		    continue;// not in use
+		};
+		match graphics::Image::from_path(ctx, src) {
+		    Ok(new) => *image.0.borrow_mut() = new,
+		    Err(error) => errors.push(format!("{}: {}", src.display(), error)),
+		}
+	    },
+	}
+    }
+
+    // an image within an animation changing means rereading it too, and scripts
+    // are compiled again on their next use
+    if animations || scripts || changed.iter().any(|src| src.extension().is_some_and(|extension| extension == "png")) {
+	GENERATION.set(GENERATION.get() + 1);
+    }
+    if !specs.is_empty() {
+	errors.extend(crate::ship::data::reload(ctx, &specs).iter().map(ToString::to_string));
+    }
+    errors
+}
+
+// notices files under resources changing by polling when they were modified
+// only the folders that hold specs, scripts and what is in use are looked at,
+// not the whole of resources
+pub struct Watcher {
+    modified: HashMap<PathBuf, SystemTime>,
+    folders: Vec<PathBuf>,
+    next: Instant,
+}
+
+impl Watcher {
+    const INTERVAL: Duration = Duration::new(0, 500_000_000);
+
+    pub fn new() -> Watcher {
+	let folders = watched();
+	let mut modified = HashMap::new();
+	for folder in &folders {
+	    scan(folder, &mut modified);
+	}
+	Watcher {
+	    modified,
+	    folders,
+	    next: Instant::now() + Self::INTERVAL,
+	}
+    }
+
+    // paths as ggez names them, from the root of resources
+    pub fn poll(&mut self, now: Instant) -> Vec<PathBuf> {
+	if now < self.next {
+	    return Vec::new();
+	}
+	self.next = now + Self::INTERVAL;
+
+	let folders = watched();
+	let mut modified = HashMap::new();
+	for folder in &folders {
+	    scan(folder, &mut modified);
+	}
+	// a folder only now in use has nothing to compare against
+	let known = |path: &PathBuf| path.parent().is_some_and(|folder| self.folders.iter().any(|known| known == folder));
+	let mut changed: Vec<PathBuf> = modified.iter()
+	    .filter(|(path, time)| known(path) && self.modified.get(*path) != Some(*time))
+	    .map(|(path, _)| path.clone())
+	    .chain(self.modified.keys().filter(|path| !modified.contains_key(*path)).cloned())
+	    .filter_map(|path| path.strip_prefix(ROOT).ok().map(|path| Path::new("/").join(path)))
+	    .collect();
+	changed.sort();
+	self.modified = modified;
+	self.folders = folders;
+	changed
+    }
+}
+
+// the folders under resources holding specs, scripts, and images and
+// animations that have been loaded
+fn watched() -> Vec<PathBuf> {
+    let mut sources: Vec<PathBuf> = Vec::new();
+    IMAGES.with_borrow(|images| sources.extend(images.keys().cloned()));
+    ANIMATIONS.with_borrow(|animations| sources.extend(animations.keys().cloned()));
+    let mut folders: Vec<PathBuf> = sources.iter()
+	.filter_map(|src| src.parent()?.strip_prefix("/").ok())
+	.chain([Path::new("specs"), Path::new("scripts")])
+	.map(|folder| Path::new(ROOT).join(folder))
+	.collect();
+    folders.sort();
+    folders.dedup();
+    folders
+}
+
+// the files directly within a folder
+fn scan(folder: &Path, modified: &mut HashMap<PathBuf, SystemTime>) {
+    let Ok(entries) = std::fs::read_dir(folder) else {
+	return;
+    };
+    for entry in entries.flatten() {
+	let Ok(metadata) = entry.metadata() else {
+	    continue;
+	};
+	if let (true, Ok(time)) = (metadata.is_file(), metadata.modified()) {
+	    modified.insert(entry.path(), time);
+	}
+    }
+}
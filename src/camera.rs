@@ -0,0 +1,178 @@
+// What part of the arena is on screen. The Director keeps the view from one
+// frame to the next, following the ships as the camera mode says, and Tab
+// moves on to the next mode during a match.
+use crate::config::{self, CameraMode};
+use crate::ship::{self, units};
+use crate::dim::Dimensionless;
+use crate::layout::Layout;
+use ggez::Context;
+use ggez::input::mouse::{self, MouseButton};
+use std::num::NonZeroU8;
+
+type Point = (units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Camera {
+    pub left: f32,
+    pub top: f32,
+    pub scale: units::TrueSpaceUnitInv<f32>,
+    pub centre: Point,// of the view, not always within the arena
+}
+
+impl Camera {
+    // the middle and the zoom that fit the camera ships, seen from near where the last view was
+    fn fit(ships: &[ship::Actor], previous: Option<Camera>, layout: &Layout) -> Option<(Point, units::TrueSpaceUnitInv<f32>)> {
+	const MARGIN: units::TrueSpaceUnit<f32> = units::TrueSpaceUnit::new(360.0);
+
+	let mut left = f32::INFINITY * units::TSU;
+	let mut top = f32::INFINITY * units::TSU;
+	let mut right = -f32::INFINITY * units::TSU;
+	let mut bottom = -f32::INFINITY * units::TSU;
+
+	// every ship where it appears nearest the first
+	let mut reference = None;
+	for ship in ships {
+	    if ship.has_camera() {
+		let position = ship.get_pos();
+		let (originx, originy) = *reference.get_or_insert(position);
+		let (dx, dy) = ship::wrap((position.0 - originx, position.1 - originy));
+		let (x, y) = (originx + dx, originy + dy);
+		if x < left {
+		    left = x;
+		}
+		if x > right {
+		    right = x;
+		}
+		if y < top {
+		    top = y;
+		}
+		if y > bottom {
+		    bottom = y;
+		}
+	    }
+	}
+
+	if left == f32::INFINITY * units::TSU {
+	    return None;// no ships of signifigance found
+	}
+
+	let centre = ((left + right) * 0.5, (top + bottom) * 0.5);
+	let centre = previous.map_or(centre, |previous| previous.near(centre));
+
+	let scalex = layout.playfield.w / (right - left + MARGIN * 2.0);
+	let scaley = layout.playfield.h / (bottom - top + MARGIN * 2.0);
+	let scale = if scalex < scaley {scalex} else {scaley};
+	Some((centre, scale))
+    }
+
+    // centre in the middle of the playfield
+    fn at(centre: Point, scale: units::TrueSpaceUnitInv<f32>, layout: &Layout) -> Camera {
+	let middle = layout.playfield.center();
+	Camera {
+	    left: *(centre.0 * scale).value() - middle.x,
+	    top: *(centre.1 * scale).value() - middle.y,
+	    scale,
+	    centre,
+	}
+    }
+
+    // where a point appears nearest the middle of the view
+    pub fn near(&self, (x, y): Point) -> Point {
+	let (dx, dy) = ship::wrap((x - self.centre.0, y - self.centre.1));
+	(self.centre.0 + dx, self.centre.1 + dy)
+    }
+}
+
+pub struct Director {
+    mode: CameraMode,
+    follow: NonZeroU8,
+    zoom: units::TrueSpaceUnitInv<f32>,// when following, on a screen 1080 high
+    step: usize,// into STEPS
+    last: Option<Camera>,
+    wheel: f32,// notches turned since the last view
+}
+
+impl Director {
+    // pixels to a TSU on a screen 1080 high, closest first
+    const STEPS: [f32; 4] = [1.0, 0.5, 0.25, 0.125];
+    // how much room there must be before zooming back in a step
+    const HYSTERESIS: f32 = 1.25;
+    const DAMPING: f32 = 0.25;// seconds for the zoom to go most of the way
+    const WHEELZOOM: f32 = 1.2;// a notch
+
+    pub fn new(ctx: &mut Context) -> Self {
+	let config = config::get().camera;
+	if config.mode == CameraMode::Free {
+	    mouse::set_cursor_hidden(ctx, false);
+	}
+	Director {
+	    mode: config.mode,
+	    follow: config.follow,
+	    zoom: config.zoom * units::TSUI,
+	    step: 0,
+	    last: None,
+	    wheel: 0.0,
+	}
+    }
+
+    pub fn next_mode(&mut self, ctx: &mut Context) {
+	let index = CameraMode::ALL.iter().position(|mode| *mode == self.mode).unwrap_or(0);
+	self.mode = CameraMode::ALL[(index + 1) % CameraMode::ALL.len()];
+	mouse::set_cursor_hidden(ctx, self.mode != CameraMode::Free);
+    }
+
+    pub fn scroll(&mut self, notches: f32) {
+	self.wheel += notches;
+    }
+
+    pub fn view(&mut self, ctx: &Context, ships: &[ship::Actor], layout: &Layout) -> Camera {
+	let at = |centre, scale| Camera::at(centre, scale, layout);
+	let fit = Camera::fit(ships, self.last, layout);
+	let camera = match self.mode {
+	    CameraMode::Fit => fit.map(|(centre, scale)| at(centre, scale)),
+	    CameraMode::Stepped => fit.map(|(centre, scale)| {
+		let scale = *(scale / units::TSUI).value() / layout.ui;
+		while self.step + 1 < Self::STEPS.len() && scale < Self::STEPS[self.step] {
+		    self.step += 1;
+		}
+		while self.step > 0 && scale >= Self::STEPS[self.step - 1] * Self::HYSTERESIS {
+		    self.step -= 1;
+		}
+		// past the last step, as when the arena does not wrap, it carries on smoothly
+		let step = Self::STEPS[self.step];
+		let scale = if self.step + 1 == Self::STEPS.len() {scale.min(step)} else {step};
+		at(centre, scale * layout.ui * units::TSUI)
+	    }),
+	    CameraMode::Smooth => fit.map(|(centre, scale)| {
+		let from = self.last.map_or(scale, |last| last.scale);
+		let factor = 1.0 - (-ctx.time.delta().as_secs_f32() / Self::DAMPING).exp();
+		at(centre, from + (scale - from) * factor)
+	    }),
+	    CameraMode::Follow => ships.iter()
+		.find(|ship| ship.has_camera() && ship.side() == Some(self.follow))
+		.map(|ship| {
+		    let position = ship.get_pos();
+		    at(self.last.map_or(position, |last| last.near(position)), self.zoom * layout.ui)
+		})
+		.or(self.last.map(|last| at(last.centre, self.zoom * layout.ui))),
+	    CameraMode::Free => {
+		let last = self.last.or(fit.map(|(centre, scale)| at(centre, scale)));
+		last.map(|last| {
+		    let scale = last.scale * Self::WHEELZOOM.powf(self.wheel);
+		    let mut centre = last.centre;
+		    if ctx.mouse.button_pressed(MouseButton::Left) {
+			let delta = ctx.mouse.delta();
+			centre.0 -= delta.x / scale;
+			centre.1 -= delta.y / scale;
+		    }
+		    at(centre, scale)
+		})
+	    },
+	};
+	// nothing to look at, so the middle of the arena
+	let camera = camera.unwrap_or_else(|| at((0.0 * units::TSU, 0.0 * units::TSU), layout.ui * units::TSUI));
+	self.last = Some(camera);
+	self.wheel = 0.0;
+	camera
+    }
+}
@@ -0,0 +1,45 @@
+// Game time, which runs slow for a moment when a ship is lost. Anything that
+// moves or waits goes by this rather than by the wall clock, so that it all
+// slows together.
+use ggez::Context;
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static NOW: Cell<Option<Instant>> = const { Cell::new(None) };
+    static DELTA: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+    static SLOW: Cell<(Duration, f32)> = const { Cell::new((Duration::ZERO, 1.0)) };// real time left, and the rate until then
+}
+
+pub fn now() -> Instant {
+    NOW.get().unwrap_or_else(|| {
+	let now = Instant::now();
+	NOW.set(Some(now));
+	now
+    })
+}
+
+// game time that passed in the last frame
+pub fn delta() -> Duration {
+    DELTA.get()
+}
+
+// moves on by a frame, once each update
+pub fn tick(ctx: &Context) -> Instant {
+    let real = ctx.time.delta();
+    let (left, rate) = SLOW.get();
+    let delta = if left.is_zero() {real} else {real.mul_f32(rate)};
+    SLOW.set((left.saturating_sub(real), rate));
+    DELTA.set(delta);
+    let now = now() + delta;
+    NOW.set(Some(now));
+    now
+}
+
+// runs at rate for a while of real time, unless already slowed for longer
+pub fn slow(duration: Duration, rate: f32) {
+    let (left, _) = SLOW.get();
+    if duration > left {
+	SLOW.set((duration, rate));
+    }
+}
@@ -0,0 +1,201 @@
+// Settings from resources/config.toml, read once at startup. Anything left
+// out keeps its default:
+//
+//   [arena]
+//   wrap = true               # false for space without edges
+//   width = 8192.0            # TSU, as is height
+//   height = 8192.0
+//
+//   [camera]
+//   mode = "stepped"          # or "fit", "smooth", "follow" or "free"
+//   follow = 1                # the side followed
+//   zoom = 0.5                # when following, pixels to a TSU on a screen 1080 high
+//
+//   [window]
+//   mode = "borderless"       # or "fullscreen" or "windowed"
+//   width = 1920.0            # when windowed, as is height
+//   height = 1080.0
+use serde::Deserialize;
+use std::num::NonZeroU8;
+use std::sync::OnceLock;
+
+const PATH: &str = "./resources/config.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub arena: Arena,
+    pub camera: Camera,
+    pub window: Window,
+}
+
+// a torus, leaving one edge comes back in at the other
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Arena {
+    pub wrap: bool,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+	Arena {
+	    wrap: true,
+	    width: 8192.0,
+	    height: 8192.0,
+	}
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CameraMode {
+    Fit,// every ship, as closely as they allow
+    Stepped,// as fit, but in steps as UQM did, held a while before zooming back in
+    Smooth,// as fit, but easing into each new zoom
+    Follow,// one side, at a fixed zoom
+    Free,// dragged around with the mouse, the wheel zooming
+}
+
+impl CameraMode {
+    // in the order Tab goes through them
+    pub const ALL: [CameraMode; 5] = [CameraMode::Fit, CameraMode::Stepped, CameraMode::Smooth, CameraMode::Follow, CameraMode::Free];
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Camera {
+    pub mode: CameraMode,
+    pub follow: NonZeroU8,
+    pub zoom: f32,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+	Camera {
+	    mode: CameraMode::Stepped,
+	    follow: NonZeroU8::MIN,
+	    zoom: 0.5,
+	}
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WindowMode {
+    Borderless,// fullscreen at the desktop resolution
+    Fullscreen,// changing the display mode
+    Windowed,// resizable
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Window {
+    pub mode: WindowMode,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Default for Window {
+    fn default() -> Self {
+	Window {
+	    mode: WindowMode::Borderless,
+	    width: 1920.0,
+	    height: 1080.0,
+	}
+    }
+}
+
+impl Config {
+    fn validate(&self) -> Result<(), String> {
+	for (name, value) in [("arena width", self.arena.width), ("arena height", self.arena.height), ("camera zoom", self.camera.zoom), ("window width", self.window.width), ("window height", self.window.height)] {
+	    if !(value.is_finite() && value > 0.0) {
+		return Err(format!("{name} must be more than 0, not {value}"));
+	    }
+	}
+	Ok(())
+    }
+}
+
+fn parse(text: &str) -> Result<Config, String> {
+    let config: Config = toml::from_str(text).map_err(|error| error.to_string())?;
+    config.validate()?;
+    Ok(config)
+}
+
+// reads the file, returning what was wrong with it, in which case the defaults stand
+pub fn load() -> Option<String> {
+    let text = match std::fs::read_to_string(PATH) {
+	Ok(text) => text,
+	Err(error) if error.kind() == std::io::ErrorKind::NotFound => return None,
+	Err(error) => return Some(format!("{PATH}: {error}")),
+    };
+    match parse(&text) {
+	Ok(config) => {
+	    let _ = CONFIG.set(config);
+	    None
+	},
+	Err(error) => Some(format!("{PATH}: {error}")),
+    }
+}
+
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_keeps_defaults() {
+	let config = parse("").unwrap();
+	assert!(config.arena.wrap);
+	assert_eq!(config.arena.width, 8192.0);
+	assert_eq!(config.camera.mode, CameraMode::Stepped);
+	assert_eq!(config.window.mode, WindowMode::Borderless);
+    }
+
+    #[test]
+    fn reads_what_is_given() {
+	let config = parse("[arena]\nwrap = false\nwidth = 4096.0\n[camera]\nmode = \"free\"\nfollow = 2\n[window]\nmode = \"windowed\"\nheight = 720.0\n").unwrap();
+	assert!(!config.arena.wrap);
+	assert_eq!(config.arena.width, 4096.0);
+	assert_eq!(config.arena.height, 8192.0);
+	assert_eq!(config.camera.mode, CameraMode::Free);
+	assert_eq!(config.camera.follow.get(), 2);
+	assert_eq!(config.window.mode, WindowMode::Windowed);
+	assert_eq!(config.window.height, 720.0);
+    }
+
+    #[test]
+    fn rejects_sizes_that_are_not_positive() {
+	for text in [
+	    "[arena]\nwidth = 0.0",
+	    "[arena]\nheight = -1.0",
+	    "[arena]\nwidth = nan",
+	    "[arena]\nwidth = inf",
+	    "[camera]\nzoom = 0.0",
+	    "[window]\nwidth = -1920.0",
+	    "[window]\nheight = 0.0",
+	] {
+	    assert!(parse(text).is_err(), "{text}");
+	}
+    }
+
+    #[test]
+    fn rejects_unknown_values() {
+	for text in [
+	    "[camera]\nmode = \"sideways\"",
+	    "[camera]\nfollow = 0",
+	    "[window]\nmode = \"tiny\"",
+	    "[arena]\ndepth = 1.0",
+	    "[sound]",
+	] {
+	    assert!(parse(text).is_err(), "{text}");
+	}
+    }
+}
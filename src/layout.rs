@@ -0,0 +1,48 @@
+// Where things go on screen, worked out from the drawable size each frame so
+// that any shape of display or window works, even as it is resized. The
+// captain panels stack down the right, or on a screen at least twice as wide
+// as it is tall, flank the playfield one to a side.
+use ggez::Context;
+use ggez::{graphics, glam};
+
+// the panel images, and the screen height the game was first laid out for
+const PANELWIDTH: f32 = 256.0;
+const PANELHEIGHT: f32 = 476.0;
+const PANELSTEP: f32 = 600.0;// from the top of one stacked panel to the next
+const DESIGNHEIGHT: f32 = 1080.0;
+const ULTRAWIDE: f32 = 2.0;
+
+#[derive(Debug, Clone)]
+pub struct Layout {
+    pub playfield: graphics::Rect,
+    pub panels: [glam::Vec2; 2],// by side
+    pub ui: f32,// scale for panels, and for zoom so the view is the same on any resolution
+}
+
+impl Layout {
+    pub fn new(ctx: &Context) -> Layout {
+	let (width, height) = ctx.gfx.drawable_size();
+	// both stacked panels must fit, and leave some playfield
+	let ui = (height / DESIGNHEIGHT).min(height / (PANELSTEP + PANELHEIGHT)).min(width * 0.25 / PANELWIDTH);
+	let panelwidth = PANELWIDTH * ui;
+
+	let (playfield, panels) = if width >= height * ULTRAWIDE {
+	    let top = (height - PANELHEIGHT * ui) * 0.5;
+	    (
+		graphics::Rect::new(panelwidth, 0.0, width - panelwidth * 2.0, height),
+		[glam::vec2(0.0, top), glam::vec2(width - panelwidth, top)],
+	    )
+	} else {
+	    (
+		graphics::Rect::new(0.0, 0.0, width - panelwidth, height),
+		[glam::vec2(width - panelwidth, 0.0), glam::vec2(width - panelwidth, PANELSTEP * ui)],
+	    )
+	};
+
+	Layout {
+	    playfield,
+	    panels,
+	    ui,
+	}
+    }
+}
@@ -1,24 +1,40 @@
 use ggez::{Context, ContextBuilder, GameResult};
 use ggez::event::{self, EventHandler};
 use ggez::{conf, graphics, glam};
-use ggez::input::mouse;
 use ggez::input::keyboard::KeyCode;
+use ggez::input::mouse;
 use std::num::NonZeroU8;
 mod stats;
 mod ship;
+mod assets;
+mod packs;
+mod ani;
+mod clock;
+mod config;
+mod camera;
+mod layout;
+use camera::Camera;
+use ani::{AniError, AniErrorKind, Frame};
 use ship::units;
-use std::path::PathBuf;
-use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
 use rand::{RngCore, SeedableRng};
 #[macro_use]
 extern crate dimensioned as dim;
 use dim::Dimensionless;
 
-fn screensize() -> (f32, f32) {
-    (1920.0, 1080.0)
-}
+const ASTEROIDS: usize = 5;
 
 fn main() {
+    // before the window, which it will come to say how to open
+    if let Some(error) = config::load() {
+	assets::problem(error);
+    }
+    let window = config::get().window;
+    let fullscreen = match window.mode {
+	config::WindowMode::Borderless => conf::FullscreenType::Desktop,
+	config::WindowMode::Fullscreen => conf::FullscreenType::True,
+	config::WindowMode::Windowed => conf::FullscreenType::Windowed,
+    };
     let (mut ctx, event_loop) = ContextBuilder::new("spacefight", "Russell VA3BSP <rmorland@tutanota.com>")
 	.window_setup(conf::WindowSetup {
 	    title: "spacefight".to_owned(),
@@ -29,7 +45,9 @@ fn main() {
 	})
 	.window_mode(
 	    conf::WindowMode::default()
-		.dimensions(1920.0, 1080.0)
+		.dimensions(window.width, window.height)
+		.fullscreen_type(fullscreen)
+		.resizable(true)
 	)
 	.add_resource_path("./resources")
         .build()
@@ -44,11 +62,32 @@ struct MainState {
     ships: Vec<ship::Actor>,
     captains: Vec<graphics::Image>,
     stars: Starfield,
+    watcher: assets::Watcher,
+    reports: Vec<String>,// from the last reload that had changes, and anything missing
+    lost: Vec<NonZeroU8>,// sides whose ship was destroyed
+    director: camera::Director,
 }
 
 impl EventHandler for MainState {
     fn update(&mut self, ctx: &mut Context) -> GameResult {
-	let time = std::time::Instant::now();
+	let time = clock::tick(ctx);
+
+	// edits to resources apply to the running match
+	let changed = self.watcher.poll(std::time::Instant::now());
+	if !changed.is_empty() {
+	    let generation = assets::generation();
+	    self.reports = assets::reload(ctx, &changed);
+	    if assets::generation() != generation {
+		if let Ok(stars) = Animation::try_new(ctx, &self.stars.src) {
+		    self.stars.stars = stars;
+		}
+	    }
+	}
+	self.reports.extend(assets::take_problems());
+
+	if ctx.keyboard.is_key_just_pressed(KeyCode::Tab) {
+	    self.director.next_mode(ctx);
+	}
 
 	let mut extra = Vec::new();
 	for index in 0..self.ships.len() {
@@ -63,10 +102,23 @@ impl EventHandler for MainState {
 	while index > 0 {
 	    index -= 1;
 	    if self.ships[index].dead() {
-		self.ships.remove(index);
+		let dead = self.ships.remove(index);
+		self.ships.extend(dead.remains(ctx));
+		if let Some(event) = dead.lost() {
+		    self.event(event);
+		}
 	    }
 	}
 
+	ship::particles::update();
+
+	// destroyed or harvested asteroids are replaced
+	let asteroids = self.ships.iter().filter(|ship| ship.is_asteroid()).count();
+	for _ in asteroids..ASTEROIDS {
+	    let asteroid = ship::gen_asteroid(ctx, time, &self.ships);
+	    self.ships.push(asteroid);
+	}
+
 	for index in 1..self.ships.len() {
 	    let (left, right) = self.ships.split_at_mut(index);
 	    let dest = &mut right[0];
@@ -79,13 +131,9 @@ impl EventHandler for MainState {
     }
 
     fn draw(&mut self, ctx: &mut Context) -> GameResult {
-	let time = std::time::Instant::now();
-	let window = ctx.gfx.window();
-	if let Some(monitor) = window.current_monitor() {
-	    window.set_inner_size(monitor.size());
-	}
-
-	let camera = Camera::new(&self.ships);
+	let time = clock::now();
+	let layout = layout::Layout::new(ctx);
+	let camera = self.director.view(ctx, &self.ships, &layout);
 
 	
         let mut canvas = graphics::Canvas::from_frame(ctx, graphics::Color::BLACK);
@@ -97,163 +145,173 @@ impl EventHandler for MainState {
 	    let (main, after) = notbefore.split_at_mut(1);
 	    main[0].draw(ctx, &mut canvas, camera, time, before.iter().chain(after.iter()))?;
 	}
+	ship::particles::draw(ctx, &mut canvas, camera);
 
-	static POSITIONS: [(f32, f32); 2] = [(1664.0, 0.0), (1664.0, 600.0)];
-	for (captain, (x, y)) in self.captains.iter().zip(&POSITIONS) {
+	for (side, (captain, panel)) in self.captains.iter().zip(layout.panels).enumerate() {
+	    // a side that lost its ship is greyed out
+	    let shade = if self.lost.iter().any(|lost| lost.get() as usize == side + 1) {0.35} else {1.0};
 	    canvas.draw(
 		captain,
 		graphics::DrawParam::default()
-		    .dest(glam::vec2(*x, *y))
+		    .dest(panel)
+		    .scale(glam::vec2(layout.ui, layout.ui))
+		    .color(graphics::Color::new(shade, shade, shade, 1.0))
+	    );
+	}
+
+	if !self.reports.is_empty() {
+	    canvas.draw(
+		&graphics::Text::new(self.reports.join("\n")),
+		graphics::DrawParam::default()
+		    .dest(glam::vec2(16.0, 16.0))
+		    .color(graphics::Color::RED)
 	    );
 	}
 	
         canvas.finish(ctx)
     }
+
+    fn mouse_wheel_event(&mut self, _ctx: &mut Context, _x: f32, y: f32) -> GameResult {
+	self.director.scroll(y);
+	Ok(())
+    }
 }
 
-impl MainState {    
+impl MainState {
+    // the game mode, such as it is
+    fn event(&mut self, event: ship::Event) {
+	const BEAT: std::time::Duration = std::time::Duration::new(1, 0);
+	match event {
+	    ship::Event::ShipLost(side) => {
+		// a moment to watch it go
+		clock::slow(BEAT, 0.25);
+		self.lost.push(side);
+	    },
+	}
+    }
+    
     pub fn new(ctx: &mut Context) -> MainState {
-	let window = ctx.gfx.window();
-	let monitor = window.current_monitor();
-	window.set_fullscreen(Some(ggez::winit::window::Fullscreen::Borderless(monitor)));
+	// the ships are flown from the keyboard, only the free camera brings the cursor back
 	mouse::set_cursor_hidden(ctx, true);
 
-	let time = std::time::Instant::now();
-	let (cruiser, cruisercaptain) = ship::specs::Cruiser::gen(
-	    ctx,
-	    ((-860.0 * units::TSU, -440.0 * units::TSU), 0.0), time,
+	// packs go first, as specs and scripts may use what they bring
+	for error in packs::mount(ctx) {
+	    assets::problem(error);
+	}
+
+	// balance changes from resources/specs, bad ones are reported and left out
+	for error in ship::data::load(ctx) {
+	    assets::problem(error);
+	}
+
+	// ships may be chosen by name on the command line, built-in or scripted
+	let mut args = std::env::args().skip(1);
+	let first = args.next().unwrap_or_else(|| "cruiser".to_owned());
+	let second = args.next().unwrap_or_else(|| "avenger".to_owned());
+	// then a planet and starfield, which packs may also bring
+	// a name that is not known is reported and the default used instead
+	let planet = match args.next().as_deref() {
+	    None | Some("rainbow") => "/scenery/planets/rainbow.png".to_owned(),
+	    Some(name) => packs::planet(name).unwrap_or_else(|| {
+		let known = std::iter::once("rainbow".to_owned()).chain(packs::planets()).collect::<Vec<_>>().join(", ");
+		assets::problem(format!("unknown planet {name}, try one of {known}"));
+		"/scenery/planets/rainbow.png".to_owned()
+	    }),
+	};
+	let starfield = match args.next().as_deref() {
+	    None | Some("stars") => "/scenery/stars.ani".to_owned(),
+	    Some(name) => packs::starfield(name).unwrap_or_else(|| {
+		let known = std::iter::once("stars".to_owned()).chain(packs::starfields()).collect::<Vec<_>>().join(", ");
+		assets::problem(format!("unknown starfield {name}, try one of {known}"));
+		"/scenery/stars.ani".to_owned()
+	    }),
+	};
+	let time = clock::now();
+	let firstplace = ((-860.0 * units::TSU, -440.0 * units::TSU), 0.0);
+	let (firstship, firstcaptain) = ship::gen_ship(
+	    ctx, &first,
+	    firstplace, time,
 	    NonZeroU8::new(1).unwrap(),
 	    ship::UserControl.into(),
-	);
-	let (avenger, avengercaptain) = ship::specs::Avenger::gen(
-	    ctx,
-	    ((860.0 * units::TSU, 440.0 * units::TSU), 0.0), time,
+	).unwrap_or_else(|error| {
+	    assets::problem(error);
+	    ship::gen_ship(ctx, "cruiser", firstplace, time, NonZeroU8::new(1).unwrap(), ship::UserControl.into()).expect("cruiser is built in")
+	});
+	let secondplace = ((860.0 * units::TSU, 440.0 * units::TSU), 0.0);
+	let (secondship, secondcaptain) = ship::gen_ship(
+	    ctx, &second,
+	    secondplace, time,
 	    NonZeroU8::new(2).unwrap(),
 	    ship::NoControl.into(),
-	);
+	).unwrap_or_else(|error| {
+	    assets::problem(error);
+	    ship::gen_ship(ctx, "avenger", secondplace, time, NonZeroU8::new(2).unwrap(), ship::NoControl.into()).expect("avenger is built in")
+	});
         MainState {
 	    ships: vec![
 		ship::gen_planet(
-		    ctx,
+		    ctx, &planet,
 		    ((0.0 * units::TSU, 0.0 * units::TSU), 0.0), time,
 		),
-		cruiser.with_camera(true),
-		avenger.with_camera(true),
+		firstship.with_camera(true),
+		secondship.with_camera(true),
 	    ],
-	    captains: vec![cruisercaptain, avengercaptain],
-	    stars: Starfield {stars: Animation::new(ctx, "/scenery/stars.ani")},
+	    captains: vec![firstcaptain, secondcaptain],
+	    stars: Starfield {
+		stars: Animation::new(ctx, &starfield),
+		src: starfield,
+	    },
+	    watcher: assets::Watcher::new(),
+	    reports: assets::take_problems(),
+	    lost: Vec::new(),
+	    director: camera::Director::new(ctx),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-struct Camera {
-    left: f32,
-    top: f32,
-    scale: units::TrueSpaceUnitInv<f32>,
-}
-
-impl Camera {
-    fn new(ships: &[ship::Actor]) -> Self {
-	const MARGIN: units::TrueSpaceUnit<f32> = units::TrueSpaceUnit::new(360.0);
-
-	let mut left = f32::INFINITY * units::TSU;
-	let mut top = f32::INFINITY * units::TSU;
-	let mut right = -f32::INFINITY * units::TSU;
-	let mut bottom = -f32::INFINITY * units::TSU;
-
-	for ship in ships {
-	    if ship.has_camera() {
-		let (x, y) = ship.get_pos();
-		if x < left {
-		    left = x;
-		}
-		if x > right {
-		    right = x;
-		}
-		if y < top {
-		    top = y;
-		}
-		if y > bottom {
-		    bottom = y;
-		}
-	    }
-	}
-
-	if left == f32::INFINITY * units::TSU {
-	    // no ships of signifigance found, make default
-	    return Camera {
-		left: 0.0,
-		top: 0.0,
-		scale: units::TSUI,
-	    }
-	}
-
-	left -= MARGIN;
-	top -= MARGIN;
-	right += MARGIN;
-	bottom += MARGIN;
-
-	let screenwidth: f32 = 1920.0 - 288.0 * 2.0;// TODO: determine programatically
-	let screenheight: f32 = 1080.0f32;
-
-	let scalex = screenwidth / (right - left);
-	let scaley = screenheight / (bottom - top);
-	let scale = if scalex < scaley {scalex} else {scaley};
-
-	let x = (left + right) * 0.5 * scale - 288.0f32;
-	let y = (top + bottom) * 0.5 * scale;
-
-	let screenleft = *(x - screenwidth * 0.5).value();
-	let screentop = *(y - screenheight * 0.5).value();
-
-	Camera {
-	    left: screenleft,
-	    top: screentop,
-	    scale,
-	}
-    }
-}
-
 #[derive(Debug, Clone)]
 struct Animation<const N: usize> {
     fields: [Image; N],
 }
 
 impl<const N: usize> Animation<N> {
+    // a missing or broken animation is reported and drawn as placeholders
     fn new(ctx: &mut Context, src: impl Into<PathBuf>) -> Animation<N> {
-	let mut src = src.into();
-	let spec = ctx.fs.open(&src).expect("missing animation file");
-	let reader = BufReader::new(spec);
-	let mut fields = Vec::new();
-	for line in reader.lines() {
-	    fields.push(Image::from_line(ctx, &mut src, &line.expect("valid_animation_file")));
-	}
-	Animation {
-	    fields: fields.try_into().expect("wrong length of animation"),
-	}
+	Self::try_new(ctx, src).unwrap_or_else(|error| {
+	    assets::problem(&error);
+	    let image = Image::placeholder(ctx);
+	    Animation {
+		fields: std::array::from_fn(|_| image.clone()),
+	    }
+	})
+    }
+
+    fn try_new(ctx: &mut Context, src: impl Into<PathBuf>) -> Result<Animation<N>, AniError> {
+	let src = src.into();
+	let AnimationVar {fields} = AnimationVar::try_new(ctx, &src)?;
+	let found = fields.len();
+	Ok(Animation {
+	    fields: Vec::from(fields).try_into().map_err(|_| AniError::new(&src, None, AniErrorKind::Length {expected: N, found}))?,
+	})
     }
 }
 
-/*#[derive(Debug, Clone)]
+#[derive(Debug, Clone)]
 struct AnimationVar {
     fields: Box<[Image]>,
 }
 
 impl AnimationVar {
-    fn new(ctx: &mut Context, src: impl Into<PathBuf>) -> AnimationVar {
-	let mut src = src.into();
-	let spec = ctx.fs.open(&src).expect("missing animation file");
-	let reader = BufReader::new(spec);
-	let mut fields = Vec::new();
-	for line in reader.lines() {
-	    fields.push(Image::from_line(ctx, &mut src, &line.expect("valid_animation_file")));
-	}
-	AnimationVar {
+    fn try_new(ctx: &mut Context, src: impl Into<PathBuf>) -> Result<AnimationVar, AniError> {
+	let src = src.into();
+	let fields = ani::read(ctx, &src)?.into_iter()
+	    .map(|(line, frame)| Image::from_frame(ctx, &src, frame).map_err(|kind| AniError::new(&src, Some(line), kind)))
+	    .collect::<Result<Vec<_>, _>>()?;
+	Ok(AnimationVar {
 	    fields: fields.into_boxed_slice(),
-	}
+	})
     }
-}*/
+}
 
 #[derive(Debug, Clone)]
 struct Image {
@@ -263,26 +321,25 @@ struct Image {
 }
 
 impl Image {
-    // will not affect any except last of src
-    fn from_line(ctx: &mut Context, src: &mut PathBuf, line: &str) -> Image {
-	let mut elements = line.split_whitespace();
-	let filename = elements.next().ok_or(line).expect("missing image");
-	src.set_file_name(filename);
-	let image = graphics::Image::from_path(ctx, &src).expect("missing image");
-	
-	// always 0 and 1 respectively
-	elements.next();
-	elements.next();
-
-	let absolutex: f32 = elements.next().ok_or(line).expect("missing element").parse().expect("invalid value");
-	let absolutey: f32 = elements.next().ok_or(line).expect("missing element").parse().expect("invalid value");
-	let offsetx = absolutex / image.width() as f32;
-	let offsety = absolutey / image.height() as f32;
-
-	Image {
+    // the image is named relative to the animation
+    fn from_frame(ctx: &mut Context, ani: &Path, frame: Frame) -> Result<Image, AniErrorKind> {
+	let file = ani.with_file_name(&frame.file);
+	let image = graphics::Image::from_path(ctx, &file).map_err(|error| AniErrorKind::Image {file, error})?;
+	let offsetx = frame.hotspotx / image.width() as f32;
+	let offsety = frame.hotspoty / image.height() as f32;
+
+	Ok(Image {
 	    image,
 	    offsetx,
 	    offsety,
+	})
+    }
+
+    fn placeholder(ctx: &mut Context) -> Image {
+	Image {
+	    image: assets::placeholder(ctx),
+	    offsetx: 0.5,
+	    offsety: 0.5,
 	}
     }
 
@@ -294,6 +351,7 @@ impl Image {
     
 struct Starfield {
     stars: Animation<3>,
+    src: String,
 }
 
 impl Starfield {
@@ -303,7 +361,7 @@ impl Starfield {
 	Self::draw_plane(&self.stars.fields[0].image, 1.0, 0x100, ctx, canvas, camera);
     }
     fn draw_plane(image: &graphics::Image, paralax: f32, frequency: u32, ctx: &mut Context, canvas: &mut graphics::Canvas, camera: Camera) {
-	let (screenwidth, screenheight) = screensize();
+	let (screenwidth, screenheight) = ctx.gfx.drawable_size();
 	
 	let left = camera.left * paralax - screenwidth * 0.5 * (1.0 - paralax);
 	let top = camera.top * paralax - screenheight * 0.5 * (1.0 - paralax);
@@ -0,0 +1,195 @@
+// Content packs from ./mods, either folders or zip files, added beneath
+// ./resources. A pack called acme keeps everything in an acme folder, so
+// /acme/... (a zip holds the acme folder itself, and anything beside it is
+// left out), and /acme/pack.toml says what it brings:
+//
+//   name = "Acme Fleet"
+//   ships = ["lancer"]              # scripted, from /acme/scripts/lancer.rhai
+//   planets = ["planets/ice.png"]
+//   starfields = ["stars.ani"]
+//
+// which then go by acme:lancer, acme:ice and acme:stars.
+use ggez::Context;
+use std::cell::RefCell;
+use std::io::{Cursor, Read};
+use std::path::Path;
+use serde::Deserialize;
+
+const ROOT: &str = "./mods";
+
+thread_local! {
+    static PACKS: RefCell<Vec<Pack>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    name: String,
+    #[serde(default)]
+    ships: Vec<String>,
+    #[serde(default)]
+    planets: Vec<String>,
+    #[serde(default)]
+    starfields: Vec<String>,
+}
+
+struct Pack {
+    id: String,
+    manifest: Manifest,
+}
+
+impl Pack {
+    // a path within the pack from one given relative to it
+    fn path(&self, src: &str) -> String {
+	format!("/{}/{}", self.id, src.trim_start_matches('/'))
+    }
+
+    // what a file is called, namespaced by the pack
+    fn name(&self, src: &str) -> String {
+	let stem = Path::new(src).file_stem().and_then(|stem| stem.to_str()).unwrap_or(src);
+	format!("{}:{}", self.id, stem)
+    }
+}
+
+// mounts every pack, returning what was wrong with them
+pub fn mount(ctx: &mut Context) -> Vec<String> {
+    let mut errors = Vec::new();
+    let Ok(entries) = std::fs::read_dir(ROOT) else {
+	return errors;// no packs
+    };
+
+    // ids already used at the top of resources would hide the pack's files
+    let taken: Vec<String> = ctx.fs.read_dir("/")
+	.map(|entries| entries.filter_map(|entry| entry.file_name().and_then(|name| name.to_str()).map(str::to_owned)).collect())
+	.unwrap_or_default();
+
+    let mut entries: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+    entries.sort();
+    let mut ids = Vec::new();
+    for path in entries {
+	let fail = |message: String| format!("{}: {}", path.display(), message);
+	let zipped = path.extension().is_some_and(|extension| extension == "zip");
+	if !zipped && !path.is_dir() {
+	    continue;
+	}
+	let Some(id) = path.file_stem().and_then(|stem| stem.to_str()).map(str::to_owned) else {
+	    continue;
+	};
+	if id.is_empty() || !id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-') {
+	    errors.push(fail(format!("pack id {id:?} may only have a-z, 0-9, _ and -")));
+	    continue;
+	}
+	if taken.contains(&id) || ids.contains(&id) {
+	    errors.push(fail(format!("pack id {id:?} is already in use")));
+	    continue;
+	}
+
+	ids.push(id.clone());
+
+	let staged = if zipped {stage_zip(&path, &id)} else {stage_folder(&path, &id)};
+	let mut staged = match staged {
+	    Ok(staged) => staged,
+	    Err(error) => {
+		errors.push(fail(error.to_string()));
+		continue;
+	    },
+	};
+	let src = format!("/{id}/pack.toml");
+	let mut text = String::new();
+	let read = staged.by_name(&format!("{id}/pack.toml")).map_err(|error| error.to_string())
+	    .and_then(|mut file| file.read_to_string(&mut text).map_err(|error| error.to_string()));
+	if let Err(error) = read {
+	    errors.push(fail(format!("no {src}: {error}")));
+	    continue;
+	}
+	let manifest: Manifest = match toml::from_str(&text) {
+	    Ok(manifest) => manifest,
+	    Err(error) => {
+		errors.push(fail(format!("{src}: {error}")));
+		continue;
+	    },
+	};
+	// only now that the pack is sound does anything of it show
+	if let Err(error) = ctx.fs.add_zip_file(staged.into_inner()) {
+	    errors.push(fail(error.to_string()));
+	    continue;
+	}
+
+	let pack = Pack {id, manifest};
+	let missing = pack.manifest.ships.iter().map(|ship| pack.path(&format!("scripts/{ship}.rhai")))
+	    .chain(pack.manifest.planets.iter().map(|planet| pack.path(planet)))
+	    .chain(pack.manifest.starfields.iter().map(|starfield| pack.path(starfield)))
+	    .filter(|src| !ctx.fs.exists(src));
+	for src in missing {
+	    errors.push(fail(format!("{} lists {src}, which is missing", pack.manifest.name)));
+	}
+	PACKS.with_borrow_mut(|packs| packs.push(pack));
+    }
+    errors
+}
+
+// Each pack is copied into a zip in memory holding nothing but its own folder,
+// and added alone, so neither stray files in ./mods nor anything else in a
+// zip can stand in for resources.
+type Staged = zip::ZipArchive<Cursor<Vec<u8>>>;
+
+fn stage_folder(path: &Path, id: &str) -> zip::result::ZipResult<Staged> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    let mut folders = vec![path.to_owned()];
+    while let Some(folder) = folders.pop() {
+	for entry in std::fs::read_dir(&folder)?.flatten() {
+	    let file = entry.path();
+	    if file.is_dir() {
+		folders.push(file);
+		continue;
+	    }
+	    let Some(within) = file.strip_prefix(path).ok().and_then(Path::to_str) else {
+		continue;
+	    };
+	    writer.start_file(format!("{id}/{}", within.replace('\\', "/")), options)?;
+	    std::io::copy(&mut std::fs::File::open(&file)?, &mut writer)?;
+	}
+    }
+    zip::ZipArchive::new(writer.finish()?)
+}
+
+fn stage_zip(path: &Path, id: &str) -> zip::result::ZipResult<Staged> {
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path)?)?;
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let prefix = format!("{id}/");
+    for index in 0..archive.len() {
+	let file = archive.by_index_raw(index)?;
+	if file.name().starts_with(&prefix) {
+	    writer.raw_copy_file(file)?;
+	}
+    }
+    zip::ZipArchive::new(writer.finish()?)
+}
+
+// whether a pack declares this ship, as pack:ship
+pub fn has_ship(name: &str) -> bool {
+    PACKS.with_borrow(|packs| packs.iter().any(|pack| pack.manifest.ships.iter().any(|ship| pack.name(ship) == name)))
+}
+
+pub fn planet(name: &str) -> Option<String> {
+    PACKS.with_borrow(|packs| packs.iter().find_map(|pack| pack.manifest.planets.iter().find(|planet| pack.name(planet) == name).map(|planet| pack.path(planet))))
+}
+
+pub fn starfield(name: &str) -> Option<String> {
+    PACKS.with_borrow(|packs| packs.iter().find_map(|pack| pack.manifest.starfields.iter().find(|starfield| pack.name(starfield) == name).map(|starfield| pack.path(starfield))))
+}
+
+// every planet and starfield the packs bring, for listing alongside the built-in ones
+pub fn planets() -> Vec<String> {
+    PACKS.with_borrow(|packs| packs.iter().flat_map(|pack| pack.manifest.planets.iter().map(|planet| pack.name(planet))).collect())
+}
+
+pub fn starfields() -> Vec<String> {
+    PACKS.with_borrow(|packs| packs.iter().flat_map(|pack| pack.manifest.starfields.iter().map(|starfield| pack.name(starfield))).collect())
+}
+
+// every ship the packs declare, for listing alongside the built-in ones
+pub fn ships() -> Vec<String> {
+    PACKS.with_borrow(|packs| packs.iter().flat_map(|pack| pack.manifest.ships.iter().map(|ship| pack.name(ship))).collect())
+}
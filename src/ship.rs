@@ -1,4 +1,4 @@
-use std::f32::consts::TAU;
+use std::f32::consts::{PI, TAU};
 use ggez::{Context, GameResult};
 use ggez::{graphics, glam};
 use enum_dispatch::enum_dispatch;
@@ -6,12 +6,21 @@ use std::time::{Instant, Duration};
 use std::num::NonZeroU8;
 pub mod specs;
 pub mod units;
+pub mod data;
+pub mod script;
 mod collision;
+mod animation;
+pub mod particles;
+use animation::{AnimationPlayer, AnimationEvent};
 use crate::dim::{Sqrt, Dimensionless};
 use super::Camera;
 use core::slice::Iter;
 use std::iter::Chain;
 use std::ops::BitOr;
+use std::rc::{Rc, Weak};
+use std::cell::Cell;
+use crate::assets::{self, SharedImage};
+use crate::clock;
 
 #[derive(Debug, Clone, Copy)]
 struct Gravity(u8);
@@ -32,6 +41,17 @@ enum Hitbox {
     None,
     Circle {radius: units::TrueSpaceUnit<f32>},
     Line {length: units::TrueSpaceUnit<f32>, radius: units::TrueSpaceUnit<f32>},
+    Arc {radius: units::TrueSpaceUnit<f32>, halfangle: f32},// sector centered on the direction
+}
+
+impl Hitbox {
+    // the length of a line, for weapons that place or aim by it
+    fn length(&self) -> units::TrueSpaceUnit<f32> {
+	match *self {
+	    Hitbox::Line {length, ..} => length,
+	    _ => 0.0 * units::TSU,
+	}
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -40,6 +60,7 @@ enum ObjectType {
     Asteroid,
     Ship,
     Projectile,
+    Pickup,// only touches ships
 }
 
 // Silent takes priority
@@ -48,7 +69,7 @@ enum CollisionType {
     Kinetic,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ActorSpec {
     maxspeed: units::TrueSpaceUnitPerSecond<f32>,// 24 times MAX_THRUST
     acceleration: units::TrueSpaceUnitPerSecond2<f32>,// 576 times THRUST_INCREMENT / (THRUST_WAIT + 1)
@@ -60,14 +81,137 @@ pub struct ActorSpec {
     hitbox: Hitbox,
     objecttype: ObjectType,
     takesdamage: bool,
+    forcedthrust: bool,// throttle is always full
     pub maxcrew: u8,
     pub maxbattery: u8,
     chargetime: Duration,
     chargevalue: u8,
     pub species: &'static str,
     pub captainsrc: Option<&'static str>,
+    image: &'static str,
+    sprites: Option<SpriteSheet>,// drawn instead of rotating the image
+    animations: Animations,
+    particles: Emitters,
+    weapon: Weapon,
+}
+
+// pre-rendered facings, clockwise from pointing up as in the UQM assets
+#[derive(Debug, Clone, Copy)]
+pub struct SpriteSheet {
+    src: &'static str,
+    blend: bool,// fade between the two nearest facings
+}
+
+// an .ani played over time, rather than picked by facing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimationSpec {
+    src: &'static str,
+    frametime: Duration,
+    playback: Playback,
+    cues: &'static [(usize, &'static str)],// frames that raise AnimationEvent::Cue
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Playback {
+    Once,// then holds the last frame
+    Loop,
+    PingPong,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Animations {
+    body: Option<AnimationSpec>,// drawn unturned instead of the image, as for spinning projectiles
+    thrust: Option<AnimationSpec>,// drawn beneath and turned with the actor while it thrusts
+    explosion: &'static [AnimationSpec],// played in turn where it died
+}
+
+impl Animations {
+    pub const NONE: Animations = Animations {
+	body: None,
+	thrust: None,
+	explosion: &[],
+    };
+}
+
+// what particles look like as they are given off
+#[derive(Debug, Clone, Copy)]
+pub struct Emitter {
+    rate: f32,// a second, or a hit for impacts
+    life: Duration,// at most, some go sooner
+    speed: units::TrueSpaceUnitPerSecond<f32>,// at most, relative to the emitter
+    spread: f32,// radians either side of the heading
+    size: units::TrueSpaceUnit<f32>,
+    color: graphics::Color,
+    fade: graphics::Color,// by the end of its life
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Emitters {
+    exhaust: Option<Emitter>,// from the stern while thrusting
+    trail: Option<Emitter>,// left behind all the time
+    impact: Option<Emitter>,// sparks from a hit on something solid
+}
+
+impl Emitters {
+    pub const NONE: Emitters = Emitters {
+	exhaust: None,
+	trail: None,
+	impact: None,
+    };
+}
+
+// what a projectile does and what it takes to fire, for those that are fired
+#[derive(Debug, Clone, Copy)]
+pub struct Weapon {
+    damage: u8,
+    cost: u8,// battery for each firing
+    cooldown: Duration,// between firings
+    ttl: Duration,
+    speed: units::TrueSpaceUnitPerSecond<f32>,// on leaving the ship, relative to it
+    offset: units::TrueSpaceUnit<f32>,// ahead of where it is fired from
 }
 
+impl Weapon {
+    pub const NONE: Weapon = Weapon {
+	damage: 0,
+	cost: 0,
+	cooldown: Duration::ZERO,
+	ttl: Duration::ZERO,
+	speed: units::TrueSpaceUnitPerSecond::new(0.0),
+	offset: units::TrueSpaceUnit::new(0.0),
+    };
+}
+
+pub const EXHAUST: Emitter = Emitter {
+    rate: 60.0,
+    life: Duration::new(0, 400_000_000),
+    speed: units::TrueSpaceUnitPerSecond::new(240.0),
+    spread: 0.3,
+    size: units::TrueSpaceUnit::new(3.0),
+    color: graphics::Color::new(1.0, 0.85, 0.4, 1.0),
+    fade: graphics::Color::new(0.8, 0.1, 0.0, 0.0),
+};
+
+pub const SPARKS: Emitter = Emitter {
+    rate: 16.0,
+    life: Duration::new(0, 300_000_000),
+    speed: units::TrueSpaceUnitPerSecond::new(480.0),
+    spread: PI,
+    size: units::TrueSpaceUnit::new(2.0),
+    color: graphics::Color::new(1.0, 1.0, 0.8, 1.0),
+    fade: graphics::Color::new(1.0, 0.5, 0.0, 0.0),
+};
+
+pub const IONTRAIL: Emitter = Emitter {
+    rate: 40.0,
+    life: Duration::new(0, 500_000_000),
+    speed: units::TrueSpaceUnitPerSecond::new(24.0),
+    spread: PI,
+    size: units::TrueSpaceUnit::new(2.0),
+    color: graphics::Color::new(0.6, 0.8, 1.0, 0.8),
+    fade: graphics::Color::new(0.2, 0.2, 1.0, 0.0),
+};
+
 pub struct Actor {
     native: ActorNative,
     generator: ActorGeneratorEnum,
@@ -75,14 +219,24 @@ pub struct Actor {
 }
 
 impl Actor {
+    const ARCSTEPS: usize = 64;// most points of a line tested against an arc
+
     fn damage(&mut self, damage: u8) {
 	if self.native.specs.takesdamage {
+	    let damage = self.translator.damaged(&mut self.native, &mut self.generator, damage);
 	    self.native.crew = self.native.crew.saturating_sub(damage);
 	    if self.native.crew == 0 {
 		self.native.dead = true;
 	    }
 	}
     }
+
+    // returns how much crew was taken on
+    fn heal(&mut self, crew: u8) -> u8 {
+	let taken = crew.min(self.native.specs.maxcrew.saturating_sub(self.native.crew));
+	self.native.crew += taken;
+	taken
+    }
     
     pub fn get_pos(&self) -> (units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>) {
 	(self.native.x, self.native.y)
@@ -96,10 +250,60 @@ impl Actor {
     pub fn has_camera(&self) -> bool {
 	self.native.maintaincamera
     }
+
+    pub fn side(&self) -> Option<NonZeroU8> {
+	self.native.affiliation
+    }
     
     pub fn dead(&self) -> bool {
 	self.native.dead
     }
+
+    // what is left behind once dead: the explosion where it died, and for a ship debris
+    // the remains are on no side, so nothing takes aim at them
+    pub fn remains(&self, ctx: &mut Context) -> Vec<Actor> {
+	const DEBRISCOUNT: usize = 8;
+	const MINSPEED: f32 = 120.0;
+	const MAXSPEED: f32 = 600.0;
+	let mut remains = Vec::new();
+	let position = (self.native.x, self.native.y);
+	let velocity = (self.native.dx, self.native.dy);
+
+	let stages = self.native.specs.animations.explosion;
+	if let Some(stage) = Explosion::next(ctx, stages, 0) {
+	    let mut native = ActorNative::new(self.native.image.clone(), (position, 0.0), &EXPLOSION, None);
+	    native.play(stages[stage]);
+	    remains.push(Actor::new(native, NoControl.into(), Explosion {stages, stage}.into()).with_velocity(velocity));
+	}
+
+	if matches!(self.native.specs.objecttype, ObjectType::Ship) && assets::animation(ctx, DEBRISSPIN.src).is_some() {
+	    for _ in 0..DEBRISCOUNT {
+		let heading = rand::random::<f32>() * TAU;
+		let speed = (MINSPEED + rand::random::<f32>() * (MAXSPEED - MINSPEED)) * units::TSUpS;
+		let ttl = Duration::from_secs_f32(0.4 + rand::random::<f32>() * 0.8);
+		let native = ActorNative::new(self.native.image.clone(), (position, heading), &DEBRIS, None);
+		remains.push(Actor::new(native, NoControl.into(), Debris {ttl: Timer::new(clock::now(), ttl)}.into())
+			     .with_velocity((velocity.0 + speed * heading.cos(), velocity.1 + speed * heading.sin())));
+	    }
+	}
+	remains
+    }
+
+    // for the game mode, on taking it out of play
+    pub fn lost(&self) -> Option<Event> {
+	match (self.native.specs.objecttype, self.native.affiliation) {
+	    (ObjectType::Ship, Some(side)) => Some(Event::ShipLost(side)),
+	    _ => None,
+	}
+    }
+
+    fn confuse(&mut self, duration: Duration) {
+	self.native.confusion = self.native.confusion.max(duration);
+    }
+
+    pub fn is_asteroid(&self) -> bool {
+	matches!(self.native.specs.objecttype, ObjectType::Asteroid)
+    }
     
     fn new(native: ActorNative, generator: ActorGeneratorEnum, translator: ActorTranslatorEnum) -> Self {
 	Actor {
@@ -111,19 +315,7 @@ impl Actor {
 
     pub fn draw(&mut self, ctx: &mut Context, canvas: &mut graphics::Canvas, camera: Camera, time: Instant, others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
 	if !self.dead() {
-	    // units::TSU because a pixel in the images is the same as a TSU
-	    let scale = *(camera.scale * units::TSU).value();
-	    canvas.draw(
-		&self.native.image,
-		graphics::DrawParam::default()
-		    .offset(glam::vec2(0.5, 0.5))
-		    .rotation(self.native.direction)
-		    .dest(glam::vec2(
-			*(self.native.x * camera.scale).value() - camera.left,
-			*(self.native.y * camera.scale).value() - camera.top
-		    ))
-		    .scale(glam::vec2(scale, scale))
-	    );
+	    self.native.draw(ctx, canvas, camera);
 	    
 	    let input = self.generator.update(&mut self.native, &mut self.translator, ctx, others.clone())?.normalize();
 	    self.translator.update_captain(&mut self.native, &mut self.generator, ctx, input, time, others)?;
@@ -133,8 +325,11 @@ impl Actor {
     
     pub fn update(&mut self, ctx: &mut Context, time: Instant, others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Vec<Actor>> {
 	let input = self.generator.update(&mut self.native, &mut self.translator, ctx, others.clone())?.normalize();
-	let request = self.translator.update(&mut self.native, &mut self.generator, ctx, input, time, others)?;
-	self.native.update(ctx, request.steer, request.throttle, time)?;
+	let input = self.native.perturb(input);
+	let request = self.translator.update(&mut self.native, &mut self.generator, ctx, input, time, others.clone())?;
+	self.displace(request.shift, others);
+	self.native.update(ctx, &request, time)?;
+	self.translator.moved(&self.native, input);
 	Ok(request.summon)
     }
 
@@ -146,6 +341,8 @@ impl Actor {
     pub fn interact(&mut self, ctx: &mut Context, other: &mut Actor) {
 	self.gravitate(ctx, other);
 	self.collide(ctx, other);
+	self.translator.affect(&mut self.native, &mut self.generator, ctx, other);
+	other.translator.affect(&mut other.native, &mut other.generator, ctx, self);
     }
     
     fn collide<'a>(mut self: &'a mut Self, ctx: &mut Context, mut other: &'a mut Actor) {
@@ -153,7 +350,11 @@ impl Actor {
 	    return;
 	}
 
-	if matches!(self.native.specs.hitbox, Hitbox::Circle{..}) && matches!(other.native.specs.hitbox, Hitbox::Line{..}) {
+	if matches!(self.native.specs.objecttype, ObjectType::Pickup) && !matches!(other.native.specs.objecttype, ObjectType::Ship) || matches!(other.native.specs.objecttype, ObjectType::Pickup) && !matches!(self.native.specs.objecttype, ObjectType::Ship) {
+	    return;
+	}
+
+	if matches!(self.native.specs.hitbox, Hitbox::Circle{..}) && matches!(other.native.specs.hitbox, Hitbox::Line{..}) || !matches!(self.native.specs.hitbox, Hitbox::Arc{..}) && matches!(other.native.specs.hitbox, Hitbox::Arc{..}) {
 	    // swap the pointers, affects this function only
 	    std::mem::swap(&mut self, &mut other);
 	}
@@ -163,7 +364,7 @@ impl Actor {
 	    let remote = other.translator.collide(&mut other.native, &mut other.generator, ctx, self);
 	    if matches!(local, CollisionType::Kinetic) && matches!(remote, CollisionType::Kinetic) {
 		// reverse the frame that pushed us into the block
-		let time = ctx.time.delta().as_secs_f32() * units::S;
+		let time = clock::delta().as_secs_f32() * units::S;
 		self.native.x -= self.native.dx * time;
 		self.native.y -= self.native.dy * time;
 		self.native.direction -= (self.native.angularvelocity * time).value();
@@ -174,19 +375,31 @@ impl Actor {
 		other.native.direction %= TAU;
 		
 		collision::reflect(&mut self.native, &mut other.native, normal, angularlocal, angularremote);
+
+		// the normal points away from other, sparks fly off each surface
+		let heading = normal.y.atan2(normal.x);
+		for (native, heading) in [(&self.native, heading + PI), (&other.native, heading)] {
+		    if let Some(impact) = native.specs.particles.impact {
+			let reach = native.reach();
+			let surface = (native.x + reach * heading.cos(), native.y + reach * heading.sin());
+			particles::emit(&impact, impact.rate as usize, surface, (native.dx, native.dy), heading);
+		    }
+		}
 	    }
 	}
     }
 
     fn contacting(&self, other: &Actor) -> Option<(nalgebra::Vector2<f32>, units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>)> {
 	use nalgebra::Vector2;
+	// measured against wherever other appears nearest
+	let (otherx, othery) = self.native.near((other.native.x, other.native.y));
 	match self.native.specs.hitbox {
 	    Hitbox::None => unreachable!(),
 	    Hitbox::Circle {radius: local} => match other.native.specs.hitbox {
 		Hitbox::None => unreachable!(),
 		Hitbox::Circle {radius: remote} => {
-		    let distx = self.native.x - other.native.x;
-		    let disty = self.native.y - other.native.y;
+		    let distx = self.native.x - otherx;
+		    let disty = self.native.y - othery;
 		    
 		    let distsq = distx*distx + disty*disty;
 		    let collisiondist = local + remote;
@@ -196,10 +409,11 @@ impl Actor {
 		    }
 		},
 		Hitbox::Line{..} => unreachable!(),
+		Hitbox::Arc{..} => unreachable!(),
 	    },
 	    Hitbox::Line {length, radius} => match other.native.specs.hitbox {
 		Hitbox::None => unreachable!(),
-		Hitbox::Circle {radius: remote} => if let Some((normal, angularlocal)) = self.line_contacting_circle((other.native.x, other.native.y), length, radius + remote) {
+		Hitbox::Circle {radius: remote} => if let Some((normal, angularlocal)) = self.line_contacting_circle((otherx, othery), length, radius + remote) {
 		    return Some((normal, angularlocal, 0.0 * units::TSU));
 		},
 		Hitbox::Line {length: remote, radius: remoteradius} => {
@@ -226,19 +440,44 @@ impl Actor {
 		    let sin = other.native.direction.sin();
 		    let offsetx = cos * remote * 0.5;
 		    let offsety = sin * remote * 0.5;
-		    if let Some((normal, angularlocal)) = self.line_contacting_circle((other.native.x + offsetx, other.native.y + offsety), length, totalradius) {
+		    if let Some((normal, angularlocal)) = self.line_contacting_circle((otherx + offsetx, othery + offsety), length, totalradius) {
 			let unit = Vector2::new(sin, -cos);
 			let product = unit.dot(&normal.normalize());
 			let angularremote = product * remote * 0.5;
 			return Some((normal, angularlocal, angularremote));
 		    }
-		    if let Some((normal, angularlocal)) = self.line_contacting_circle((other.native.x - offsetx, other.native.y - offsety), length, totalradius) {
+		    if let Some((normal, angularlocal)) = self.line_contacting_circle((otherx - offsetx, othery - offsety), length, totalradius) {
 			let unit = Vector2::new(-sin, cos);
 			let product = unit.dot(&normal.normalize());
 			let angularremote = product * remote * 0.5;
 			return Some((normal, angularlocal, angularremote));
 		    }
 		},
+		Hitbox::Arc{..} => unreachable!(),
+	    },
+	    Hitbox::Arc {radius, halfangle} => {
+		let normal = Vector2::new(self.native.direction.cos(), self.native.direction.sin());
+		let (length, remote) = match other.native.specs.hitbox {
+		    Hitbox::None => unreachable!(),
+		    Hitbox::Circle {radius: remote} => (0.0 * units::TSU, remote),
+		    Hitbox::Line {length, radius: remote} => (length, remote),
+		    Hitbox::Arc{..} => return None,// neither is solid
+		};
+		// test points along the line no further apart than its radius,
+		// or just its ends when it has none, and never too many for a frame
+		let steps = if remote > 0.0 * units::TSU {(length.value_unsafe / remote.value_unsafe.max(1.0)).ceil().min(Self::ARCSTEPS as f32) as usize} else {1};
+		let cos = other.native.direction.cos();
+		let sin = other.native.direction.sin();
+		for step in 0..=steps {
+		    let along = if steps == 0 {0.0} else {step as f32 / steps as f32 - 0.5} * length;
+		    let point = Vector2::new(
+			(otherx + cos * along - self.native.x).value_unsafe,
+			(othery + sin * along - self.native.y).value_unsafe,
+		    );
+		    if collision::sector_distance(point, self.native.direction, radius.value_unsafe, halfangle) < remote.value_unsafe {
+			return Some((normal, 0.0 * units::TSU, 0.0 * units::TSU));
+		    }
+		}
 	    },
 	}
 	None
@@ -246,6 +485,7 @@ impl Actor {
 
     fn line_contacting_circle(&self, (otherx, othery): (units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), length: units::TrueSpaceUnit<f32>, totalradius: units::TrueSpaceUnit<f32>) -> Option<(nalgebra::Vector2<f32>, units::TrueSpaceUnit<f32>)> {
 	use nalgebra::{Vector2, Matrix2, Rotation2};
+	let (otherx, othery) = self.native.near((otherx, othery));
 	let dist = Vector2::new((self.native.x - otherx).value_unsafe, (self.native.y - othery).value_unsafe);
 	let toaxis = Matrix2::from(Rotation2::new(-self.native.direction)) / length.value_unsafe;
 	let inline = toaxis * dist;// self is horizontal, from -0.5 to 0.5
@@ -282,12 +522,11 @@ impl Actor {
 	None
     }
 
-    fn gravitate(&mut self, ctx: &mut Context, other: &mut Actor) {
-	let time = ctx.time.delta().as_secs_f32() * units::S;
+    fn gravitate(&mut self, _ctx: &mut Context, other: &mut Actor) {
+	let time = clock::delta().as_secs_f32() * units::S;
 	
 	if self.native.specs.gravity.supports(Gravity::FIELD) && other.native.specs.gravity.supports(Gravity::ACCELERATE) || self.native.specs.gravity.supports(Gravity::ACCELERATE) && other.native.specs.gravity.supports(Gravity::FIELD) {
-	    let distx = self.native.x - other.native.x;
-	    let disty = self.native.y - other.native.y;
+	    let (distx, disty) = wrap((self.native.x - other.native.x, self.native.y - other.native.y));
 	    let distsq = distx*distx + disty*disty;
 	    let dist = distsq.sqrt();
 	    let factor = units::G / (distsq * dist) * time;// G t / r^3: kg^-1 s^-1
@@ -309,11 +548,51 @@ impl Actor {
 	    }
 	}
     }
+
+    // either way around, unlike contacting
+    fn touching(&self, other: &Actor) -> bool {
+	match (self.native.specs.hitbox, other.native.specs.hitbox) {
+	    (Hitbox::None, _) | (_, Hitbox::None) => false,
+	    (Hitbox::Circle{..}, Hitbox::Line{..}) | (Hitbox::Circle{..} | Hitbox::Line{..}, Hitbox::Arc{..}) => other.contacting(self).is_some(),
+	    _ => self.contacting(other).is_some(),
+	}
+    }
+
+    // moves in small steps, stopping short of anything solid in the way
+    fn displace(&mut self, (dx, dy): (units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), others: Chain<Iter<Actor>, Iter<Actor>>) {
+	const STEP: units::TrueSpaceUnit<f32> = units::TrueSpaceUnit::new(8.0);
+	let steps = (*((dx*dx + dy*dy).sqrt() / STEP).value()).ceil() as usize;
+	let (startx, starty) = (self.native.x, self.native.y);
+	for step in 1..=steps {
+	    let (x, y) = (self.native.x, self.native.y);
+	    let fraction = step as f32 / steps as f32;
+	    self.native.x = startx + dx * fraction;
+	    self.native.y = starty + dy * fraction;
+	    let blocked = others.clone().any(|other| {
+		matches!(other.native.specs.objecttype, ObjectType::Planet | ObjectType::Asteroid | ObjectType::Ship) && self.touching(other)
+	    });
+	    if blocked {
+		(self.native.x, self.native.y) = (x, y);
+		break;
+	    }
+	}
+    }
+}
+
+// The arena wraps at its edges, so an offset is taken the short way round.
+// Given a position instead, this keeps it within half the arena of the middle.
+pub fn wrap((x, y): (units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>)) -> (units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>) {
+    let arena = crate::config::get().arena;
+    if !arena.wrap {
+	return (x, y);
+    }
+    let around = |value: units::TrueSpaceUnit<f32>, size: f32| value - (value.value_unsafe / size).round() * size * units::TSU;
+    (around(x, arena.width), around(y, arena.height))
 }
 
 #[derive(Debug, Clone)]
 pub struct ActorNative {
-    image: graphics::Image,
+    image: SharedImage,
     x: units::TrueSpaceUnit<f32>,
     y: units::TrueSpaceUnit<f32>,
     direction: f32,
@@ -321,16 +600,37 @@ pub struct ActorNative {
     dx: units::TrueSpaceUnitPerSecond<f32>,
     dy: units::TrueSpaceUnitPerSecond<f32>,
     pub specs: &'static ActorSpec,
+    builtin: &'static ActorSpec,// what specs was resolved from
+    specgeneration: u64,
+    imagesrc: &'static str,// what specs said to show when image was last set
     affiliation: Option<NonZeroU8>,
+    serial: u64,// tells actors apart, as for what a weapon has already struck
     dead: bool,
     maintaincamera: bool,
     pub crew: u8,
     pub battery: u8,
     recharge: FireRate,
+    confusion: Duration,// time left with scrambled controls
+    facings: Option<(&'static str, u64, Option<crate::AnimationVar>)>,// source and generation last loaded
+    body: Option<AnimationPlayer>,
+    playing: Option<AnimationSpec>,// a body the translator chose, over what the specs say
+    thrust: Option<AnimationPlayer>,
+    thrusting: bool,
+    exhaustdue: f32,// particles owed from earlier frames
+    traildue: f32,
+    pub events: Vec<AnimationEvent>,// from the body in the last update
+}
+
+thread_local! {
+    static SERIAL: Cell<u64> = const { Cell::new(0) };
 }
 
 impl ActorNative {
-    pub fn new(image: graphics::Image, ((x, y), direction): ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), specs: &'static ActorSpec, affiliation: Option<NonZeroU8>) -> Self {
+    pub fn new(image: SharedImage, ((x, y), direction): ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), specs: &'static ActorSpec, affiliation: Option<NonZeroU8>) -> Self {
+	let builtin = specs;
+	let specs = data::resolve(builtin);
+	let serial = SERIAL.get();
+	SERIAL.set(serial + 1);
 	Self {
 	    image,
 	    x,
@@ -340,24 +640,188 @@ impl ActorNative {
 	    dx: 0.0 * units::TSUpS,
 	    dy: 0.0 * units::TSUpS,
 	    specs,
+	    builtin,
+	    specgeneration: data::generation(),
+	    imagesrc: specs.image,
 	    affiliation,
+	    serial,
 	    dead: false,
 	    maintaincamera: false,
 	    crew: specs.maxcrew,
 	    battery: specs.maxbattery,
-	    recharge: FireRate::new(specs.chargetime),
+	    recharge: FireRate::new(),
+	    confusion: Duration::ZERO,
+	    facings: None,
+	    body: specs.animations.body.map(AnimationPlayer::new),
+	    playing: None,
+	    thrust: specs.animations.thrust.map(AnimationPlayer::new),
+	    thrusting: false,
+	    exhaustdue: 0.0,
+	    traildue: 0.0,
+	    events: Vec::new(),
 	}
     }
-    
-    fn update(&mut self, ctx: &mut Context, steer: f32, throttle: f32, now: Instant) -> GameResult {
-	if self.battery != self.specs.maxbattery && self.recharge.try_fire(now) {
+
+    fn draw(&mut self, ctx: &mut Context, canvas: &mut graphics::Canvas, camera: Camera) {
+	// units::TSU because a pixel in the images is the same as a TSU
+	let scale = *(camera.scale * units::TSU).value();
+	// drawn wherever it appears nearest the middle of the view
+	let (x, y) = camera.near((self.x, self.y));
+	let dest = glam::vec2(
+	    *(x * camera.scale).value() - camera.left,
+	    *(y * camera.scale).value() - camera.top
+	);
+
+	// thrust images point right, as the plain images do
+	if let Some(thrust) = self.thrust.as_mut().filter(|_| self.thrusting) {
+	    thrust.draw(ctx, canvas, graphics::DrawParam::default()
+		.rotation(self.direction)
+		.dest(dest)
+		.scale(glam::vec2(scale, scale)));
+	}
+	if let Some(body) = &mut self.body {
+	    if body.draw(ctx, canvas, graphics::DrawParam::default().dest(dest).scale(glam::vec2(scale, scale))) {
+		return;
+	    }
+	}
+
+	// a form change may bring a different sheet, a missing sheet keeps the image
+	let generation = assets::generation();
+	if let Some(sprites) = &self.specs.sprites {
+	    if !self.facings.as_ref().is_some_and(|(src, loaded, _)| *src == sprites.src && *loaded == generation) {
+		// a broken reload keeps the last good sheet
+		let previous = self.facings.take().filter(|(src, _, _)| *src == sprites.src).and_then(|(_, _, facings)| facings);
+		let facings = crate::AnimationVar::try_new(ctx, sprites.src).ok().filter(|facings| !facings.fields.is_empty()).or(previous);
+		self.facings = Some((sprites.src, generation, facings));
+	    }
+	}
+	let facings = match (&self.specs.sprites, &self.facings) {
+	    (Some(sprites), Some((src, _, Some(facings)))) if *src == sprites.src => Some((sprites, facings)),
+	    _ => None,
+	};
+
+	let Some((sprites, facings)) = facings else {
+	    canvas.draw(
+		&self.image.get(),
+		graphics::DrawParam::default()
+		    .offset(glam::vec2(0.5, 0.5))
+		    .rotation(self.direction)
+		    .dest(dest)
+		    .scale(glam::vec2(scale, scale))
+	    );
+	    return;
+	};
+
+	// direction 0 points right, facing 0 points up
+	let count = facings.fields.len();
+	let position = (self.direction + PI * 0.5).rem_euclid(TAU) / TAU * count as f32;
+	if sprites.blend {
+	    let first = position.floor();
+	    let fraction = position - first;
+	    let first = first as usize % count;
+	    for (index, alpha) in [(first, 1.0), ((first + 1) % count, fraction)] {
+		let field = &facings.fields[index];
+		canvas.draw(
+		    &field.image,
+		    field.get_drawparam()
+			.dest(dest)
+			.scale(glam::vec2(scale, scale))
+			.color(graphics::Color::new(1.0, 1.0, 1.0, alpha))
+		);
+	    }
+	} else {
+	    let field = &facings.fields[position.round() as usize % count];
+	    canvas.draw(
+		&field.image,
+		field.get_drawparam()
+		    .dest(dest)
+		    .scale(glam::vec2(scale, scale))
+	    );
+	}
+    }
+
+    // where a point appears nearest to this, across the edges of the arena
+    fn near(&self, (x, y): (units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>)) -> (units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>) {
+	let (dx, dy) = wrap((x - self.x, y - self.y));
+	(self.x + dx, self.y + dy)
+    }
+
+    // whole particles for this frame, keeping the fraction for the next
+    fn due(owed: &mut f32, rate: f32) -> usize {
+	*owed += rate * clock::delta().as_secs_f32();
+	let count = owed.floor();
+	*owed -= count;
+	count as usize
+    }
+
+    // how far the hitbox stretches from the middle, along the direction
+    fn reach(&self) -> units::TrueSpaceUnit<f32> {
+	match self.specs.hitbox {
+	    Hitbox::None => 0.0 * units::TSU,
+	    Hitbox::Circle {radius} | Hitbox::Arc {radius, ..} => radius,
+	    Hitbox::Line {length, radius} => length * 0.5 + radius,
+	}
+    }
+
+    // plays the animation from its start, whatever the specs say
+    fn play(&mut self, animation: AnimationSpec) {
+	self.playing = Some(animation);
+	self.body = Some(AnimationPlayer::new(animation));
+    }
+
+    // for translators that switch forms, given the built-in specs of the new one
+    pub fn set_specs(&mut self, builtin: &'static ActorSpec) {
+	if !std::ptr::eq(self.builtin, builtin) {
+	    self.builtin = builtin;
+	    self.specs = data::resolve(builtin);
+	}
+    }
+
+    // while confused, the helm answers at random but the weapons still work
+    fn perturb(&self, input: Input) -> Input {
+	if self.confusion.is_zero() {
+	    return input;
+	}
+	let helm = Input::LEFT.0 | Input::RIGHT.0 | Input::THRUST.0;
+	Input(input.0 & !helm | rand::random::<u8>() & helm).normalize()
+    }
+
+    fn update(&mut self, ctx: &mut Context, request: &Request, now: Instant) -> GameResult {
+	let Request {steer, throttle, heading, impulse: (impulsex, impulsey), ..} = *request;
+	if self.specgeneration != data::generation() {
+	    self.specgeneration = data::generation();
+	    self.specs = data::resolve(self.builtin);
+	}
+	// an empty image is one chosen elsewhere, as for a planet
+	if self.specs.image != self.imagesrc {
+	    self.imagesrc = self.specs.image;
+	    if !self.imagesrc.is_empty() {
+		self.image = SharedImage::load(ctx, self.imagesrc);
+	    }
+	}
+	self.crew = self.crew.min(self.specs.maxcrew);
+	self.battery = self.battery.min(self.specs.maxbattery);
+	
+	if self.battery != self.specs.maxbattery && self.recharge.try_fire(now, self.specs.chargetime) {
 	    self.battery = self.battery.saturating_add(self.specs.chargevalue);
 	    if self.battery > self.specs.maxbattery {
 		self.battery = self.specs.maxbattery;
 	    }
 	}
 	
-	let time = ctx.time.delta().as_secs_f32() * units::S;
+	let time = clock::delta().as_secs_f32() * units::S;
+	self.confusion = self.confusion.saturating_sub(clock::delta());
+
+	AnimationPlayer::follow(&mut self.body, self.playing.or(self.specs.animations.body));
+	AnimationPlayer::follow(&mut self.thrust, self.specs.animations.thrust);
+	self.events.clear();
+	if let Some(body) = &mut self.body {
+	    body.advance(ctx, clock::delta(), &mut self.events);
+	}
+
+	// instantaneous change, such as recoil, is not subject to maxspeed
+	self.dx += impulsex;
+	self.dy += impulsey;
 	
 	let targetangularvelocity = self.specs.turnspeed * steer;
 	let startangularvelocity = self.angularvelocity;
@@ -384,9 +848,30 @@ impl ActorNative {
 	self.direction += *(centerangularvelocity * time).value();
 	self.direction %= TAU;
 
+	let throttle = if self.specs.forcedthrust {1.0} else {throttle};
+	// the flame starts over with each burn
+	let thrusting = throttle != 0.0;
+	if let Some(thrust) = &mut self.thrust {
+	    if thrusting {
+		thrust.advance(ctx, clock::delta(), &mut Vec::new());
+	    } else if self.thrusting {
+		thrust.restart();
+	    }
+	}
+	self.thrusting = thrusting;
+	if let Some(exhaust) = self.specs.particles.exhaust.filter(|_| thrusting) {
+	    let count = Self::due(&mut self.exhaustdue, exhaust.rate);
+	    let reach = self.reach();
+	    let stern = (self.x - reach * self.direction.cos(), self.y - reach * self.direction.sin());
+	    particles::emit(&exhaust, count, stern, (self.dx, self.dy), self.direction + PI);
+	}
+	if let Some(trail) = self.specs.particles.trail {
+	    let count = Self::due(&mut self.traildue, trail.rate);
+	    particles::emit(&trail, count, (self.x, self.y), (0.0 * units::TSUpS, 0.0 * units::TSUpS), self.direction + PI);
+	}
 	if throttle != 0.0 {
-	    let a_x = throttle * self.specs.acceleration * centraldirection.cos();
-	    let a_y = throttle * self.specs.acceleration * centraldirection.sin();
+	    let a_x = throttle * self.specs.acceleration * (centraldirection + heading).cos();
+	    let a_y = throttle * self.specs.acceleration * (centraldirection + heading).sin();
 
 	    self.dx += a_x * time;
 	    self.dy += a_y * time;
@@ -410,6 +895,7 @@ impl ActorNative {
 
 	self.x += (startdx + self.dx) * 0.5 * time;
 	self.y += (startdy + self.dy) * 0.5 * time;
+	(self.x, self.y) = wrap((self.x, self.y));
 	
         Ok(())
     }
@@ -499,6 +985,9 @@ impl ActorGenerator for UserControl {
 struct Request {
     steer: f32,
     throttle: f32,
+    heading: f32,// direction of thrust relative to the ship
+    impulse: (units::TrueSpaceUnitPerSecond<f32>, units::TrueSpaceUnitPerSecond<f32>),
+    shift: (units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>),// a jump in position, short of anything solid
     summon: Vec<Actor>,
 }
 
@@ -507,6 +996,9 @@ impl Request {
 	Self {
 	    steer,
 	    throttle,
+	    heading: 0.0,
+	    impulse: (0.0 * units::TSUpS, 0.0 * units::TSUpS),
+	    shift: (0.0 * units::TSU, 0.0 * units::TSU),
 	    summon: Vec::new(),
 	}
     }
@@ -517,6 +1009,14 @@ trait ActorTranslator {
     fn update(&mut self, native: &mut ActorNative, generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request>;
     fn collide(&mut self, native: &mut ActorNative, generator: &mut ActorGeneratorEnum, ctx: &mut Context, other: &mut Actor) -> CollisionType;
     fn update_captain(&mut self, native: &mut ActorNative, generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult;
+    // called for every other actor each frame, in contact or not
+    fn affect(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) {}
+    // the victim decides how much of the damage gets through
+    fn damaged(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, damage: u8) -> u8 {
+	damage
+    }
+    // once the actor has moved for the frame, for anything that follows it
+    fn moved(&mut self, _native: &ActorNative, _input: Input) {}
 }
 
 impl ActorTranslator for Box<dyn ActorTranslator> {
@@ -529,14 +1029,51 @@ impl ActorTranslator for Box<dyn ActorTranslator> {
     fn update_captain(&mut self, native: &mut ActorNative, generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
 	(&mut **self).update_captain(native, generator, ctx, input, time, others)
     }
+    fn affect(&mut self, native: &mut ActorNative, generator: &mut ActorGeneratorEnum, ctx: &mut Context, other: &mut Actor) {
+	(**self).affect(native, generator, ctx, other)
+    }
+    fn damaged(&mut self, native: &mut ActorNative, generator: &mut ActorGeneratorEnum, damage: u8) -> u8 {
+	(**self).damaged(native, generator, damage)
+    }
+    fn moved(&mut self, native: &ActorNative, input: Input) {
+	(**self).moved(native, input)
+    }
 }
 
 #[enum_dispatch]
 enum ActorTranslatorEnum {
     Planet,
+    Asteroid,
     Avenger(specs::Avenger),
     Cruiser(specs::Cruiser),
     CruiserMissile(specs::CruiserMissile),
+    Mauler(specs::Mauler),
+    Shot(specs::Shot),
+    Stinger(specs::Stinger),
+    Tongue(specs::Tongue),
+    Avatar(specs::Avatar),
+    ZapSat(specs::ZapSat),
+    Penetrator(specs::Penetrator),
+    CrewPod(specs::CrewPod),
+    Podship(specs::Podship),
+    Plasmoid(specs::Plasmoid),
+    Guardian(specs::Guardian),
+    Bubble(specs::Bubble),
+    Jugger(specs::Jugger),
+    Torch(specs::Torch),
+    Flame(specs::Flame),
+    Drone(specs::Drone),
+    Cone(specs::Cone),
+    Probe(specs::Probe),
+    Marauder(specs::Marauder),
+    Blade(specs::Blade),
+    SupoxBlade(specs::SupoxBlade),
+    Trader(specs::Trader),
+    Blast(specs::Blast),
+    Pulse(specs::Pulse),
+    RingFlame(specs::RingFlame),
+    Explosion,
+    Debris,
     Other(Box<dyn ActorTranslator>),
 }
 
@@ -548,6 +1085,9 @@ impl ActorTranslator for Planet {
 	    Request {
 		steer: 0.0,
 		throttle: 0.0,
+		heading: 0.0,
+		impulse: (0.0 * units::TSUpS, 0.0 * units::TSUpS),
+		shift: (0.0 * units::TSU, 0.0 * units::TSU),
 		summon: Vec::new(),
 	    }
 	)
@@ -567,8 +1107,8 @@ impl ActorTranslator for Planet {
     }
 }
 
-pub fn gen_planet(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant) -> Actor {
-    let image = graphics::Image::from_path(ctx, "/scenery/planets/rainbow.png").expect("missing image");
+pub fn gen_planet(ctx: &mut Context, src: &str, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant) -> Actor {
+    let image = SharedImage::load(ctx, src);
 
     let native = ActorNative::new(image, position, &PLANET, None);
     Actor::new(native, NoControl.into(), Planet.into())
@@ -585,12 +1125,240 @@ pub static PLANET: ActorSpec = ActorSpec {
     hitbox: Hitbox::Circle {radius: units::TrueSpaceUnit::new(150.0)},
     objecttype: ObjectType::Planet,
     takesdamage: false,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "",// chosen for each match
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon::NONE,
+};
+
+struct Asteroid;
+
+impl ActorTranslator for Asteroid {
+    fn update(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	Ok(Request::new(0.0, 0.0))
+    }
+
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+// things happening in the match that the game mode hears of
+#[derive(Debug, Clone, Copy)]
+pub enum Event {
+    ShipLost(NonZeroU8),// the side it fought for
+}
+
+struct Explosion {
+    stages: &'static [AnimationSpec],
+    stage: usize,
+}
+
+impl Explosion {
+    // the first stage from index on that can be shown
+    fn next(ctx: &mut Context, stages: &'static [AnimationSpec], index: usize) -> Option<usize> {
+	(index..stages.len()).find(|&stage| assets::animation(ctx, stages[stage].src).is_some())
+    }
+}
+
+impl ActorTranslator for Explosion {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if native.events.contains(&AnimationEvent::Finished) {
+	    match Self::next(ctx, self.stages, self.stage + 1) {
+		Some(stage) => {
+		    self.stage = stage;
+		    native.play(self.stages[stage]);
+		},
+		None => native.dead = true,
+	    }
+	}
+	Ok(Request::new(0.0, 0.0))
+    }
+
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+// the ship explosion from UQM, then the smoke clearing
+pub const BOOM: AnimationSpec = AnimationSpec {
+    src: "/scenery/boom-big.ani",
+    frametime: Duration::new(0, 50_000_000),
+    playback: Playback::Once,
+    cues: &[],
+};
+
+pub const SMOKE: AnimationSpec = AnimationSpec {
+    src: "/scenery/smoke.ani",
+    frametime: Duration::new(0, 80_000_000),
+    playback: Playback::Once,
+    cues: &[],
+};
+
+pub const SHIPEXPLOSION: &[AnimationSpec] = &[BOOM, SMOKE];
+
+pub static EXPLOSION: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(0.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(1.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::None,
+    objecttype: ObjectType::Projectile,
+    takesdamage: false,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "",// whatever it was that exploded
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon::NONE,
+};
+
+struct Debris {
+    ttl: Timer,
+}
+
+impl ActorTranslator for Debris {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	native.dead |= self.ttl.done(time);
+	Ok(Request::new(0.0, 0.0))
+    }
+
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub const DEBRISSPIN: AnimationSpec = AnimationSpec {
+    src: "/scenery/debris.ani",
+    frametime: Duration::new(0, 60_000_000),
+    playback: Playback::Loop,
+    cues: &[],
+};
+
+pub static DEBRIS: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(1200.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(1.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::None,
+    objecttype: ObjectType::Projectile,
+    takesdamage: false,
+    forcedthrust: false,
     maxcrew: 1,
     maxbattery: 0,
     chargetime: Duration::new(0, 0),
     chargevalue: 0,
     species: "",
     captainsrc: None,
+    image: "",// whatever it broke off from
+    sprites: None,
+    animations: Animations {
+	body: Some(DEBRISSPIN),
+	..Animations::NONE
+    },
+    particles: Emitters::NONE,
+    weapon: Weapon::NONE,
+};
+
+// a built-in ship, one a pack declares as pack:ship, or failing those one from resources/scripts
+pub fn gen_ship(ctx: &mut Context, name: &str, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> Result<(Actor, graphics::Image), String> {
+    if let Some(gen) = specs::lookup(name) {
+	return Ok(gen(ctx, position, time, affiliation, generator));
+    }
+    let known = || specs::SHIPS.iter().map(|(name, _)| name.to_string()).chain(crate::packs::ships()).collect::<Vec<_>>().join(", ");
+    if name.contains(':') && !crate::packs::has_ship(name) {
+	return Err(format!("unknown ship {name}, try one of {}", known()));
+    }
+    script::gen(ctx, name, position, time, affiliation, generator).map_err(|error| format!("unknown ship {name}: {error}\ntry one of {}", known()))
+}
+
+// somewhere in the ring around the planet, drifting and spinning at random
+pub fn gen_asteroid(ctx: &mut Context, _time: Instant, others: &[Actor]) -> Actor {
+    const MINDIST: f32 = 1500.0;
+    const MAXDIST: f32 = 3000.0;
+    const MAXSPEED: f32 = 240.0;
+    const MAXSPIN: f32 = 0.5 * TAU;
+    const TRIES: usize = 16;// to find a clear spot, after which it goes anywhere
+    
+    let image = SharedImage::load(ctx, data::resolve(&ASTEROID).image);
+
+    let mut tries = 0;
+    loop {
+	let angle = rand::random::<f32>() * TAU;
+	let dist = MINDIST + rand::random::<f32>() * (MAXDIST - MINDIST);
+	let heading = rand::random::<f32>() * TAU;
+	let speed = rand::random::<f32>() * MAXSPEED;
+	let position = ((dist * angle.cos() * units::TSU, dist * angle.sin() * units::TSU), heading);
+	
+	let mut native = ActorNative::new(image.clone(), position, &ASTEROID, None);
+	native.angularvelocity = (rand::random::<f32>() * 2.0 - 1.0) * MAXSPIN * units::RADpS;
+	let asteroid = Actor::new(native, NoControl.into(), Asteroid.into())
+	    .with_velocity((speed * heading.cos() * units::TSUpS, speed * heading.sin() * units::TSUpS));
+	tries += 1;
+	if tries == TRIES || !others.iter().any(|other| asteroid.touching(other)) {
+	    break asteroid;
+	}
+    }
+}
+
+pub static ASTEROID: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(240.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(3.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(576.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {radius: units::TrueSpaceUnit::new(24.0)},
+    objecttype: ObjectType::Asteroid,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/scenery/asteroid.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters {
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -608,33 +1376,72 @@ impl Timer {
     pub fn done(self, now: Instant) -> bool {
 	now > self.endtime
     }
+
+    pub fn remaining(self, now: Instant) -> Duration {
+	self.endtime.saturating_duration_since(now)
+    }
 }
 
 impl Default for Timer {
     fn default() -> Self {
 	Timer {
-	    endtime: Instant::now(),
+	    endtime: clock::now(),
 	}
     }
 }
 
+// what a parent shares with the actors that follow it around
+#[derive(Clone, Copy, Default)]
+pub struct Anchor {
+    x: units::TrueSpaceUnit<f32>,
+    y: units::TrueSpaceUnit<f32>,
+    dx: units::TrueSpaceUnitPerSecond<f32>,
+    dy: units::TrueSpaceUnitPerSecond<f32>,
+    direction: f32,
+    input: Input,
+}
+
+// held by the parent, children keep a weak reference and die with it
+#[derive(Default)]
+pub struct Tether(Rc<Cell<Anchor>>);
+
+impl Tether {
+    pub fn set(&self, native: &ActorNative, input: Input) {
+	self.0.set(Anchor {
+	    x: native.x,
+	    y: native.y,
+	    dx: native.dx,
+	    dy: native.dy,
+	    direction: native.direction,
+	    input,
+	});
+    }
+
+    pub fn follow(&self) -> Weak<Cell<Anchor>> {
+	Rc::downgrade(&self.0)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct FireRate {
     nextshot: Instant,
-    cooldown: Duration,// which is really static, but it's small
 }
 
 impl FireRate {
-    fn new(cooldown: Duration) -> Self {
+    fn new() -> Self {
 	Self {
-	    nextshot: Instant::now(),// maybe make start at 0
-	    cooldown,
+	    nextshot: clock::now(),// maybe make start at 0
 	}
     }
 
-    fn try_fire(&mut self, now: Instant) -> bool {
+    fn ready(self, now: Instant) -> bool {
+	now > self.nextshot
+    }
+
+    // the cooldown is given each time, as specs can change underneath
+    fn try_fire(&mut self, now: Instant, cooldown: Duration) -> bool {
 	if now > self.nextshot {
-	    self.nextshot = now + self.cooldown;
+	    self.nextshot = now + cooldown;
 	    true
 	} else {
 	    false
@@ -0,0 +1,141 @@
+// Plays an AnimationSpec frame by frame, for actors that are more than one
+// image. The frames come from assets::animation, so they are shared between
+// players and follow hot reloads.
+use super::{AnimationSpec, Playback};
+use crate::assets;
+use crate::AnimationVar;
+use ggez::Context;
+use ggez::{graphics, glam};
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationEvent {
+    Cue(&'static str),// a frame named in the spec was reached
+    Looped,
+    Finished,// only with Playback::Once, or when there are no frames
+}
+
+#[derive(Debug, Clone)]
+pub struct AnimationPlayer {
+    spec: AnimationSpec,
+    frames: Option<Rc<AnimationVar>>,
+    generation: Option<u64>,// of the assets when frames was looked up
+    elapsed: Duration,// within the current frame
+    frame: usize,
+    backwards: bool,// the return half of Playback::PingPong
+    finished: bool,
+}
+
+impl AnimationPlayer {
+    pub fn new(spec: AnimationSpec) -> Self {
+	AnimationPlayer {
+	    spec,
+	    frames: None,
+	    generation: None,
+	    elapsed: Duration::ZERO,
+	    frame: 0,
+	    backwards: false,
+	    finished: false,
+	}
+    }
+
+    // keeps playing through a form change that brings the same animation
+    pub fn follow(player: &mut Option<Self>, spec: Option<AnimationSpec>) {
+	match (player.as_mut(), spec) {
+	    (Some(current), Some(spec)) if current.spec.src == spec.src => current.spec = spec,
+	    (None, None) => {},
+	    (_, spec) => *player = spec.map(Self::new),
+	}
+    }
+
+    pub fn restart(&mut self) {
+	self.elapsed = Duration::ZERO;
+	self.frame = 0;
+	self.backwards = false;
+	self.finished = false;
+    }
+
+    fn frames(&mut self, ctx: &mut Context) -> Option<Rc<AnimationVar>> {
+	let generation = assets::generation();
+	if self.generation != Some(generation) {
+	    self.generation = Some(generation);
+	    self.frames = assets::animation(ctx, self.spec.src);
+	    // a reload may have taken frames away
+	    if let Some(frames) = &self.frames {
+		self.frame = self.frame.min(frames.fields.len().saturating_sub(1));
+	    }
+	}
+	self.frames.clone()
+    }
+
+    pub fn advance(&mut self, ctx: &mut Context, delta: Duration, events: &mut Vec<AnimationEvent>) {
+	if self.finished {
+	    return;
+	}
+	let count = self.frames(ctx).map_or(0, |frames| frames.fields.len());
+	if count == 0 {
+	    self.finished = true;
+	    events.push(AnimationEvent::Finished);
+	    return;
+	}
+
+	self.elapsed += delta;
+	// a zero frametime shows a frame per advance
+	while !self.finished && self.elapsed >= self.spec.frametime {
+	    self.elapsed = self.elapsed.saturating_sub(self.spec.frametime);
+	    self.step(count, events);
+	    if self.spec.frametime.is_zero() {
+		break;
+	    }
+	}
+    }
+
+    fn step(&mut self, count: usize, events: &mut Vec<AnimationEvent>) {
+	match self.spec.playback {
+	    Playback::Once => {
+		if self.frame + 1 < count {
+		    self.frame += 1;
+		} else {
+		    self.finished = true;
+		    events.push(AnimationEvent::Finished);
+		    return;
+		}
+	    },
+	    Playback::Loop => {
+		self.frame = (self.frame + 1) % count;
+		if self.frame == 0 {
+		    events.push(AnimationEvent::Looped);
+		}
+	    },
+	    Playback::PingPong if count == 1 => events.push(AnimationEvent::Looped),
+	    Playback::PingPong => {
+		if self.backwards {
+		    self.frame -= 1;
+		    if self.frame == 0 {
+			self.backwards = false;
+			events.push(AnimationEvent::Looped);
+		    }
+		} else {
+		    self.frame += 1;
+		    if self.frame + 1 == count {
+			self.backwards = true;
+		    }
+		}
+	    },
+	}
+	events.extend(self.spec.cues.iter().filter(|(frame, _)| *frame == self.frame).map(|(_, name)| AnimationEvent::Cue(name)));
+    }
+
+    // false when there is nothing to draw, so the caller can fall back
+    pub fn draw(&mut self, ctx: &mut Context, canvas: &mut graphics::Canvas, param: graphics::DrawParam) -> bool {
+	let Some(frames) = self.frames(ctx) else {
+	    return false;
+	};
+	let Some(field) = frames.fields.get(self.frame) else {
+	    return false;
+	};
+	canvas.draw(&field.image, param.offset(glam::vec2(field.offsetx, field.offsety)));
+	true
+    }
+}
@@ -1,6 +1,7 @@
 use super::ActorNative;
 use super::units;
 use nalgebra::{Vector2, Matrix2};
+use std::f32::consts::{PI, TAU};
 
 pub fn reflect(left: &mut ActorNative, right: &mut ActorNative, normal: Vector2<f32>, angularleft: units::TrueSpaceUnit<f32>, angularright: units::TrueSpaceUnit<f32>) {
     // normal vector need not be normalized, and sign does not matter.
@@ -60,3 +61,19 @@ pub fn reflect(left: &mut ActorNative, right: &mut ActorNative, normal: Vector2<
     left.angularvelocity += leftdeltaomega;
     right.angularvelocity += rightdeltaomega;
 }
+
+pub fn sector_distance(point: Vector2<f32>, direction: f32, radius: f32, halfangle: f32) -> f32 {
+    // point is relative to the tip of the sector, zero if inside.
+    // halfangle is assumed to be less than a right angle.
+    let angle = (point.y.atan2(point.x) - direction + PI).rem_euclid(TAU) - PI;
+    if angle.abs() <= halfangle {
+	// only the rim can be out of reach
+	(point.norm() - radius).max(0.0)
+    } else {
+	// closest to the nearer straight edge
+	let edge = direction + halfangle.copysign(angle);
+	let unit = Vector2::new(edge.cos(), edge.sin());
+	let along = point.dot(&unit).clamp(0.0, radius);
+	(point - unit * along).norm()
+    }
+}
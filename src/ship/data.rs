@@ -0,0 +1,647 @@
+// Specs can be tuned without recompiling. Every .toml file in resources/specs
+// holds tables named after the specs below, and any field left out keeps its
+// built-in value. Angles are in turns throughout, and times in seconds:
+//
+//   [cruiser]
+//   maxspeed = 576.0
+//   turnspeed = 0.25          # turns per second, as is turnacceleration
+//   hitbox = { shape = "circle", radius = 19.0 }
+//   image = "/ships/cruiser/main.png"
+//
+//   [cruisermissile]
+//   chargetime = 0.0
+//
+//   [cruisermissile.weapon]   # what firing it costs and what it does
+//   damage = 4
+//   cost = 9                  # battery
+//   cooldown = 0.4            # between firings
+//   ttl = 2.5
+//   speed = 960.0             # leaving the ship
+//   offset = 128.0            # ahead of the ship
+//
+//   [avatarlaser]
+//   hitbox = { shape = "arc", radius = 150.0, halfangle = 0.0625 }
+//
+//   [probe]
+//   forcedthrust = true       # thrusts whatever the controls say
+//
+//   [cruiser.animations.thrust]
+//   src = "/ships/cruiser/flame.ani"
+//   frametime = 0.05          # seconds a frame
+//   playback = "loop"         # or "once" or "pingpong"
+//   cues = { flare = 2 }      # raised on reaching frame 2
+//
+//   [cruiser.particles.exhaust]
+//   rate = 60.0               # a second, or a hit for impact
+//   life = 0.4                # seconds
+//   speed = 240.0
+//   spread = 0.05             # turns either side
+//   size = 3.0
+//   color = [1.0, 0.85, 0.4, 1.0]
+//   fade = [0.8, 0.1, 0.0, 0.0]
+//
+// The Jugger's shield and the Torch's afterburner are forms of those ships:
+// juggershield only sets its image, sprites and weapon, and torchburn its
+// maxspeed and acceleration, taking the rest from jugger and torch.
+//
+// A spec that fails to parse or validate keeps its built-in values. What is
+// peculiar to one ship, such as the Mauler's recoil or how the Jugger spreads
+// its lances, stays in code; only the weapon table above is shared by all.
+use super::*;
+use super::specs::*;
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::collections::BTreeMap;
+use std::cell::{Cell, RefCell};
+use serde::Deserialize;
+
+static NAMED: &[(&str, &ActorSpec)] = &[
+    ("planet", &PLANET),
+    ("asteroid", &ASTEROID),
+    ("debris", &DEBRIS),
+    ("cruiser", &CRUISER),
+    ("cruisermissile", &CRUISERMISSILE),
+    ("avenger", &AVENGER),
+    ("mauler", &MAULER),
+    ("maulercannon", &MAULERCANNON),
+    ("stinger", &STINGER),
+    ("stingerspray", &STINGERSPRAY),
+    ("stingertongue", &STINGERTONGUE),
+    ("avatar", &AVATAR),
+    ("avatarlaser", &AVATARLASER),
+    ("zapsat", &ZAPSAT),
+    ("zapsatlaser", &ZAPSATLASER),
+    ("penetrator", &PENETRATOR),
+    ("penetratordagger", &PENETRATORDAGGER),
+    ("crewpod", &CREWPOD),
+    ("podship", &PODSHIP),
+    ("plasmoid", &PLASMOID),
+    ("guardian", &GUARDIAN),
+    ("blazer", &BLAZER),
+    ("bubble", &BUBBLE),
+    ("jugger", &JUGGER),
+    ("juggershield", &JUGGERSHIELD),
+    ("juggerlance", &JUGGERLANCE),
+    ("torch", &TORCH),
+    ("torchburn", &TORCHBURN),
+    ("torchhorn", &TORCHHORN),
+    ("torchflame", &TORCHFLAME),
+    ("drone", &DRONE),
+    ("dronecone", &DRONECONE),
+    ("probe", &PROBE),
+    ("probelightning", &PROBELIGHTNING),
+    ("marauder", &MARAUDER),
+    ("marauderblade", &MARAUDERBLADE),
+    ("marauderflame", &MARAUDERFLAME),
+    ("blade", &BLADE),
+    ("bladeglob", &BLADEGLOB),
+    ("trader", &TRADER),
+    ("traderblast1", &TRADERBLAST[0]),
+    ("traderblast2", &TRADERBLAST[1]),
+    ("traderblast3", &TRADERBLAST[2]),
+    ("traderblast4", &TRADERBLAST[3]),
+    ("traderpulse", &TRADERPULSE),
+];
+
+// what each of NAMED is replaced with, in the same order, and which file did it
+struct Loaded {
+    replacement: &'static ActorSpec,
+    file: PathBuf,
+}
+
+thread_local! {
+    static LOADED: RefCell<Vec<Option<Loaded>>> = const { RefCell::new(Vec::new()) };
+    // each of FORMS rebuilt on what its ship and it resolve to, once either is loaded
+    static FORMED: RefCell<Vec<Option<&'static ActorSpec>>> = const { RefCell::new(Vec::new()) };
+    static GENERATION: Cell<u64> = const { Cell::new(0) };
+}
+
+// actors resolve their specs again when this changes
+pub fn generation() -> u64 {
+    GENERATION.get()
+}
+
+// the spec to use in place of a built-in one, which is itself if none was loaded
+pub fn resolve(builtin: &'static ActorSpec) -> &'static ActorSpec {
+    if let Some(index) = FORMS.iter().position(|(form, _, _)| std::ptr::eq(*form, builtin)) {
+	return FORMED.with_borrow(|formed| formed.get(index).copied().flatten()).unwrap_or(builtin);
+    }
+    loaded(builtin)
+}
+
+// what was loaded for a built-in spec, leaving forms as they are in their file
+fn loaded(builtin: &'static ActorSpec) -> &'static ActorSpec {
+    let Some(index) = NAMED.iter().position(|(_, named)| std::ptr::eq(*named, builtin)) else {
+	return builtin;// only scripts know of it
+    };
+    LOADED.with_borrow(|loaded| loaded.get(index).and_then(|loaded| loaded.as_ref()).map_or(builtin, |loaded| loaded.replacement))
+}
+
+// a new spec built on a named one, for specs that only scripts know of
+pub fn derive(base: &str, overrides: SpecFile) -> Result<&'static ActorSpec, String> {
+    let (_, builtin) = NAMED.iter().find(|(named, _)| *named == base).ok_or_else(|| format!("no such spec {base:?}"))?;
+    Ok(Box::leak(Box::new(overrides.apply(resolve(builtin))?)))
+}
+
+#[derive(Debug)]
+pub struct SpecError {
+    file: PathBuf,
+    spec: Option<String>,
+    message: String,
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+	match &self.spec {
+	    Some(spec) => write!(f, "{}: [{}] {}", self.file.display(), spec, self.message),
+	    None => write!(f, "{}: {}", self.file.display(), self.message),
+	}
+    }
+}
+
+impl std::error::Error for SpecError {}
+
+// reads every file in /specs, returning what was wrong with them
+pub fn load(ctx: &Context) -> Vec<SpecError> {
+    let mut files: Vec<PathBuf> = match ctx.fs.read_dir("/specs") {
+	Ok(files) => files.filter(|file| file.extension().is_some_and(|extension| extension == "toml")).collect(),
+	Err(_) => Vec::new(),// nothing to override
+    };
+    files.sort();
+    reload(ctx, &files)
+}
+
+// rereads just the given files in /specs, leaving what the others set alone
+// anything that fails keeps what was loaded for it before, and a file that is
+// gone gives its specs back their built-in values
+pub fn reload(ctx: &Context, files: &[PathBuf]) -> Vec<SpecError> {
+    let mut errors = Vec::new();
+    let mut touched: Vec<&'static ActorSpec> = Vec::new();
+    LOADED.with_borrow_mut(|loaded| {
+	loaded.resize_with(NAMED.len(), || None);
+	for file in files {
+	    // whatever this file set before and does not set again
+	    let mut dropped: Vec<usize> = (0..loaded.len())
+		.filter(|&index| loaded[index].as_ref().is_some_and(|loaded| loaded.file == *file))
+		.collect();
+	    let tables = if ctx.fs.exists(file) {
+		match read(ctx, file) {
+		    Ok(tables) => tables,
+		    Err(message) => {
+			errors.push(SpecError {file: file.clone(), spec: None, message});
+			continue;
+		    },
+		}
+	    } else {
+		BTreeMap::new()
+	    };
+	    for (name, overrides) in tables {
+		let error = |message| SpecError {file: file.clone(), spec: Some(name.clone()), message};
+		let Some(index) = NAMED.iter().position(|(named, _)| *named == name) else {
+		    errors.push(error("no such spec".to_owned()));
+		    continue;
+		};
+		if loaded[index].as_ref().is_some_and(|loaded| loaded.file != *file) {
+		    errors.push(error("already set by another file".to_owned()));
+		    continue;
+		}
+		dropped.retain(|&dropped| dropped != index);
+		match overrides.apply(NAMED[index].1) {
+		    Ok(spec) => {
+			loaded[index] = Some(Loaded {replacement: Box::leak(Box::new(spec)), file: file.clone()});
+			touched.push(NAMED[index].1);
+		    },
+		    Err(message) => errors.push(error(message)),
+		}
+	    }
+	    for index in dropped {
+		loaded[index] = None;
+		touched.push(NAMED[index].1);
+	    }
+	}
+    });
+    FORMED.with_borrow_mut(|formed| {
+	formed.resize(FORMS.len(), None);
+	for (index, (form, ship, build)) in FORMS.iter().enumerate() {
+	    if touched.iter().any(|touched| std::ptr::eq(*touched, *form) || std::ptr::eq(*touched, *ship)) {
+		formed[index] = Some(Box::leak(Box::new(build(loaded(form), loaded(ship)))));
+	    }
+	}
+    });
+    GENERATION.set(GENERATION.get() + 1);
+    errors
+}
+
+fn read(ctx: &Context, file: &Path) -> Result<BTreeMap<String, SpecFile>, String> {
+    let mut text = String::new();
+    ctx.fs.open(file)
+	.map_err(|error| error.to_string())?
+	.read_to_string(&mut text)
+	.map_err(|error| error.to_string())?;
+    toml::from_str(&text).map_err(|error| error.to_string())
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpecFile {
+    maxspeed: Option<f32>,
+    acceleration: Option<f32>,
+    mass: Option<f32>,
+    turnspeed: Option<f32>,
+    turnacceleration: Option<f32>,
+    inertia: Option<f32>,
+    gravity: Option<GravityFile>,
+    hitbox: Option<HitboxFile>,
+    objecttype: Option<ObjectTypeFile>,
+    takesdamage: Option<bool>,
+    forcedthrust: Option<bool>,
+    maxcrew: Option<u8>,
+    maxbattery: Option<u8>,
+    chargetime: Option<f32>,
+    chargevalue: Option<u8>,
+    species: Option<String>,
+    captainsrc: Option<String>,
+    image: Option<String>,
+    sprites: Option<SpriteSheetFile>,
+    animations: Option<AnimationsFile>,
+    particles: Option<EmittersFile>,
+    weapon: Option<WeaponFile>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum GravityFile {
+    None,
+    Accelerate,
+    Field,
+    Full,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "shape", rename_all = "lowercase", deny_unknown_fields)]
+enum HitboxFile {
+    None,
+    Circle {radius: f32},
+    Line {length: f32, radius: f32},
+    Arc {radius: f32, halfangle: f32},
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ObjectTypeFile {
+    Planet,
+    Asteroid,
+    Ship,
+    Projectile,
+    Pickup,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SpriteSheetFile {
+    src: String,
+    #[serde(default)]
+    blend: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnimationsFile {
+    body: Option<AnimationSpecFile>,
+    thrust: Option<AnimationSpecFile>,
+    explosion: Option<Vec<AnimationSpecFile>>,// stages, in turn
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnimationSpecFile {
+    src: String,
+    frametime: f32,
+    #[serde(default)]
+    playback: PlaybackFile,
+    #[serde(default)]
+    cues: BTreeMap<String, usize>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+enum PlaybackFile {
+    #[default]
+    Once,
+    Loop,
+    PingPong,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EmittersFile {
+    exhaust: Option<EmitterFile>,
+    trail: Option<EmitterFile>,
+    impact: Option<EmitterFile>,
+}
+
+// each field left out keeps what the spec had
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WeaponFile {
+    damage: Option<u8>,
+    cost: Option<u8>,
+    cooldown: Option<f32>,
+    ttl: Option<f32>,
+    speed: Option<f32>,
+    offset: Option<f32>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EmitterFile {
+    rate: f32,
+    life: f32,
+    speed: f32,
+    #[serde(default)]
+    spread: f32,
+    size: f32,
+    color: [f32; 4],
+    fade: Option<[f32; 4]>,// the color gone transparent if left out
+}
+
+impl EmitterFile {
+    fn apply(self, name: &str) -> Result<Emitter, String> {
+	let color = |name: String, [r, g, b, a]: [f32; 4]| {
+	    for value in [r, g, b, a] {
+		if !(0.0..=1.0).contains(&value) {
+		    return Err(format!("{name} values must be from 0 to 1, not {value}"));
+		}
+	    }
+	    Ok(graphics::Color::new(r, g, b, a))
+	};
+	let [r, g, b, _] = self.color;
+	Ok(Emitter {
+	    rate: nonnegative(&format!("{name} rate"), self.rate)?,
+	    life: Duration::from_secs_f32(positive(&format!("{name} life"), self.life)?),
+	    speed: units::TrueSpaceUnitPerSecond::new(nonnegative(&format!("{name} speed"), self.speed)?),
+	    spread: nonnegative(&format!("{name} spread"), self.spread)? * TAU,
+	    size: units::TrueSpaceUnit::new(positive(&format!("{name} size"), self.size)?),
+	    color: color(format!("{name} color"), self.color)?,
+	    fade: color(format!("{name} fade"), self.fade.unwrap_or([r, g, b, 0.0]))?,
+	})
+    }
+}
+
+impl AnimationSpecFile {
+    fn apply(self, name: &str) -> Result<AnimationSpec, String> {
+	let cues: Vec<(usize, &'static str)> = self.cues.into_iter().map(|(cue, frame)| (frame, leak(cue))).collect();
+	Ok(AnimationSpec {
+	    src: resource(&format!("{name} src"), self.src)?,
+	    frametime: Duration::from_secs_f32(nonnegative(&format!("{name} frametime"), self.frametime)?),
+	    playback: match self.playback {
+		PlaybackFile::Once => Playback::Once,
+		PlaybackFile::Loop => Playback::Loop,
+		PlaybackFile::PingPong => Playback::PingPong,
+	    },
+	    cues: Box::leak(cues.into_boxed_slice()),
+	})
+    }
+}
+
+fn leak(text: String) -> &'static str {
+    Box::leak(text.into_boxed_str())
+}
+
+fn positive(name: &str, value: f32) -> Result<f32, String> {
+    if value.is_finite() && value > 0.0 {
+	Ok(value)
+    } else {
+	Err(format!("{name} must be more than 0, not {value}"))
+    }
+}
+
+fn nonnegative(name: &str, value: f32) -> Result<f32, String> {
+    if value.is_finite() && value >= 0.0 {
+	Ok(value)
+    } else {
+	Err(format!("{name} must not be negative, not {value}"))
+    }
+}
+
+fn resource(name: &str, path: String) -> Result<&'static str, String> {
+    if path.starts_with('/') {
+	Ok(leak(path))
+    } else {
+	Err(format!("{name} must start with / as it is within resources, not {path:?}"))
+    }
+}
+
+impl SpecFile {
+    fn apply(self, builtin: &ActorSpec) -> Result<ActorSpec, String> {
+	let mut spec = builtin.clone();
+
+	if let Some(value) = self.maxspeed {
+	    spec.maxspeed = units::TrueSpaceUnitPerSecond::new(nonnegative("maxspeed", value)?);
+	}
+	if let Some(value) = self.acceleration {
+	    spec.acceleration = units::TrueSpaceUnitPerSecond2::new(nonnegative("acceleration", value)?);
+	}
+	if let Some(value) = self.mass {
+	    spec.mass = units::Ton::new(positive("mass", value)?);
+	}
+	if let Some(value) = self.turnspeed {
+	    spec.turnspeed = units::RadianPerSecond::new(nonnegative("turnspeed", value)? * TAU);
+	}
+	if let Some(value) = self.turnacceleration {
+	    spec.turnacceleration = units::RadianPerSecond2::new(nonnegative("turnacceleration", value)? * TAU);
+	}
+	if let Some(value) = self.inertia {
+	    spec.inertia = units::TrueSpaceUnit2::new(positive("inertia", value)?);
+	}
+	if let Some(value) = self.gravity {
+	    spec.gravity = match value {
+		GravityFile::None => Gravity::NONE,
+		GravityFile::Accelerate => Gravity::ACCELERATE,
+		GravityFile::Field => Gravity::FIELD,
+		GravityFile::Full => Gravity::FULL,
+	    };
+	}
+	if let Some(value) = self.hitbox {
+	    spec.hitbox = match value {
+		HitboxFile::None => Hitbox::None,
+		HitboxFile::Circle {radius} => Hitbox::Circle {
+		    radius: units::TrueSpaceUnit::new(positive("hitbox radius", radius)?),
+		},
+		HitboxFile::Line {length, radius} => Hitbox::Line {
+		    length: units::TrueSpaceUnit::new(positive("hitbox length", length)?),
+		    radius: units::TrueSpaceUnit::new(positive("hitbox radius", radius)?),
+		},
+		HitboxFile::Arc {radius, halfangle} => {
+		    if !(halfangle > 0.0 && halfangle <= 0.5) {
+			return Err(format!("hitbox halfangle must be above 0 and at most half a turn, not {halfangle}"));
+		    }
+		    Hitbox::Arc {
+			radius: units::TrueSpaceUnit::new(positive("hitbox radius", radius)?),
+			halfangle: halfangle * TAU,
+		    }
+		},
+	    };
+	}
+	if let Some(value) = self.objecttype {
+	    spec.objecttype = match value {
+		ObjectTypeFile::Planet => ObjectType::Planet,
+		ObjectTypeFile::Asteroid => ObjectType::Asteroid,
+		ObjectTypeFile::Ship => ObjectType::Ship,
+		ObjectTypeFile::Projectile => ObjectType::Projectile,
+		ObjectTypeFile::Pickup => ObjectType::Pickup,
+	    };
+	}
+	if let Some(value) = self.takesdamage {
+	    spec.takesdamage = value;
+	}
+	if let Some(value) = self.forcedthrust {
+	    spec.forcedthrust = value;
+	}
+	if let Some(value) = self.maxcrew {
+	    spec.maxcrew = value;
+	}
+	if let Some(value) = self.maxbattery {
+	    spec.maxbattery = value;
+	}
+	if let Some(value) = self.chargetime {
+	    spec.chargetime = Duration::from_secs_f32(nonnegative("chargetime", value)?);
+	}
+	if let Some(value) = self.chargevalue {
+	    spec.chargevalue = value;
+	}
+	if let Some(value) = self.species {
+	    spec.species = leak(value);
+	}
+	if let Some(value) = self.captainsrc {
+	    spec.captainsrc = Some(resource("captainsrc", value)?);
+	}
+	if let Some(value) = self.image {
+	    spec.image = resource("image", value)?;
+	}
+	if let Some(value) = self.sprites {
+	    spec.sprites = Some(SpriteSheet {
+		src: resource("sprites src", value.src)?,
+		blend: value.blend,
+	    });
+	}
+	if let Some(value) = self.animations {
+	    if let Some(body) = value.body {
+		spec.animations.body = Some(body.apply("body animation")?);
+	    }
+	    if let Some(thrust) = value.thrust {
+		spec.animations.thrust = Some(thrust.apply("thrust animation")?);
+	    }
+	    if let Some(explosion) = value.explosion {
+		let stages = explosion.into_iter().map(|stage| stage.apply("explosion animation")).collect::<Result<Vec<_>, _>>()?;
+		spec.animations.explosion = Box::leak(stages.into_boxed_slice());
+	    }
+	}
+	if let Some(value) = self.particles {
+	    if let Some(exhaust) = value.exhaust {
+		spec.particles.exhaust = Some(exhaust.apply("exhaust")?);
+	    }
+	    if let Some(trail) = value.trail {
+		spec.particles.trail = Some(trail.apply("trail")?);
+	    }
+	    if let Some(impact) = value.impact {
+		spec.particles.impact = Some(impact.apply("impact")?);
+	    }
+	}
+
+	if let Some(value) = self.weapon {
+	    if let Some(damage) = value.damage {
+		spec.weapon.damage = damage;
+	    }
+	    if let Some(cost) = value.cost {
+		spec.weapon.cost = cost;
+	    }
+	    if let Some(cooldown) = value.cooldown {
+		spec.weapon.cooldown = Duration::from_secs_f32(nonnegative("weapon cooldown", cooldown)?);
+	    }
+	    if let Some(ttl) = value.ttl {
+		spec.weapon.ttl = Duration::from_secs_f32(positive("weapon ttl", ttl)?);
+	    }
+	    if let Some(speed) = value.speed {
+		spec.weapon.speed = units::TrueSpaceUnitPerSecond::new(nonnegative("weapon speed", speed)?);
+	    }
+	    if let Some(offset) = value.offset {
+		if !offset.is_finite() {
+		    return Err(format!("weapon offset must be a number, not {offset}"));
+		}
+		spec.weapon.offset = units::TrueSpaceUnit::new(offset);
+	    }
+	}
+
+	// what the rest of the game relies on
+	if spec.maxcrew == 0 {
+	    return Err("maxcrew must be at least 1".to_owned());
+	}
+	if matches!(spec.objecttype, ObjectType::Ship) && spec.captainsrc.is_none() {
+	    return Err("a ship needs a captainsrc".to_owned());
+	}
+	if spec.chargevalue > 0 && spec.chargetime.is_zero() {
+	    return Err("chargetime must be more than 0 when chargevalue is".to_owned());
+	}
+	Ok(spec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hitbox(table: &str) -> Result<Hitbox, String> {
+	let file: SpecFile = toml::from_str(&format!("hitbox = {table}")).map_err(|error| error.to_string())?;
+	file.apply(&CRUISER).map(|spec| spec.hitbox)
+    }
+
+    #[test]
+    fn reads_each_shape() {
+	assert!(matches!(hitbox(r#"{ shape = "none" }"#), Ok(Hitbox::None)));
+	assert!(matches!(hitbox(r#"{ shape = "circle", radius = 19.0 }"#), Ok(Hitbox::Circle {radius}) if radius.value_unsafe == 19.0));
+	assert!(matches!(hitbox(r#"{ shape = "line", length = 120.0, radius = 4.0 }"#),
+			 Ok(Hitbox::Line {length, radius}) if length.value_unsafe == 120.0 && radius.value_unsafe == 4.0));
+	// half a turn is the whole way round
+	assert!(matches!(hitbox(r#"{ shape = "arc", radius = 150.0, halfangle = 0.5 }"#),
+			 Ok(Hitbox::Arc {radius, halfangle}) if radius.value_unsafe == 150.0 && halfangle == 0.5 * TAU));
+    }
+
+    #[test]
+    fn rejects_sizes_that_are_not_positive() {
+	for table in [
+	    r#"{ shape = "circle", radius = 0.0 }"#,
+	    r#"{ shape = "circle", radius = -19.0 }"#,
+	    r#"{ shape = "circle", radius = nan }"#,
+	    r#"{ shape = "line", length = 0.0, radius = 4.0 }"#,
+	    r#"{ shape = "line", length = -120.0, radius = 4.0 }"#,
+	    r#"{ shape = "line", length = 120.0, radius = 0.0 }"#,
+	    r#"{ shape = "line", length = 120.0, radius = -4.0 }"#,
+	    r#"{ shape = "line", length = inf, radius = 4.0 }"#,
+	    r#"{ shape = "arc", radius = 0.0, halfangle = 0.25 }"#,
+	    r#"{ shape = "arc", radius = -150.0, halfangle = 0.25 }"#,
+	] {
+	    assert!(hitbox(table).is_err(), "{table}");
+	}
+    }
+
+    #[test]
+    fn rejects_halfangles_outside_half_a_turn() {
+	for halfangle in ["0.0", "-0.1", "0.6", "nan"] {
+	    assert!(hitbox(&format!(r#"{{ shape = "arc", radius = 150.0, halfangle = {halfangle} }}"#)).is_err(), "{halfangle}");
+	}
+    }
+
+    #[test]
+    fn rejects_unknown_and_incomplete_shapes() {
+	for table in [
+	    r#"{ shape = "square", radius = 10.0 }"#,
+	    r#"{ shape = "circle" }"#,
+	    r#"{ shape = "line", length = 120.0 }"#,
+	    r#"{ shape = "circle", radius = 10.0, length = 4.0 }"#,
+	] {
+	    assert!(hitbox(table).is_err(), "{table}");
+	}
+    }
+}
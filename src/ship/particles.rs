@@ -0,0 +1,97 @@
+// Short-lived specks for exhaust, sparks and trails. They never touch
+// anything, so they are kept apart from the actors and all drawn in one batch.
+use super::{Emitter, units};
+use crate::{clock, Camera};
+use crate::dim::Dimensionless;
+use ggez::Context;
+use ggez::{graphics, glam};
+use std::cell::RefCell;
+use std::time::Duration;
+
+// past this, new particles are dropped
+const LIMIT: usize = 4096;
+
+struct Particle {
+    x: units::TrueSpaceUnit<f32>,
+    y: units::TrueSpaceUnit<f32>,
+    dx: units::TrueSpaceUnitPerSecond<f32>,
+    dy: units::TrueSpaceUnitPerSecond<f32>,
+    age: Duration,
+    life: Duration,
+    size: units::TrueSpaceUnit<f32>,
+    color: graphics::Color,
+    fade: graphics::Color,
+}
+
+thread_local! {
+    static PARTICLES: RefCell<Vec<Particle>> = const { RefCell::new(Vec::new()) };
+    static BATCH: RefCell<Option<graphics::InstanceArray>> = const { RefCell::new(None) };
+}
+
+// count particles from a point, heading within the spread of direction on top of the velocity given
+pub(super) fn emit(emitter: &Emitter, count: usize, (x, y): (units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), (dx, dy): (units::TrueSpaceUnitPerSecond<f32>, units::TrueSpaceUnitPerSecond<f32>), direction: f32) {
+    PARTICLES.with_borrow_mut(|particles| {
+	let count = count.min(LIMIT.saturating_sub(particles.len()));
+	particles.extend((0..count).map(|_| {
+	    let heading = direction + (rand::random::<f32>() * 2.0 - 1.0) * emitter.spread;
+	    let speed = emitter.speed * (0.5 + rand::random::<f32>() * 0.5);
+	    Particle {
+		x,
+		y,
+		dx: dx + speed * heading.cos(),
+		dy: dy + speed * heading.sin(),
+		age: Duration::ZERO,
+		life: emitter.life.mul_f32(0.5 + rand::random::<f32>() * 0.5),
+		size: emitter.size,
+		color: emitter.color,
+		fade: emitter.fade,
+	    }
+	}));
+    });
+}
+
+// moves them on by a frame of game time
+pub fn update() {
+    let delta = clock::delta();
+    let time = delta.as_secs_f32() * units::S;
+    PARTICLES.with_borrow_mut(|particles| {
+	particles.retain_mut(|particle| {
+	    particle.age += delta;
+	    particle.x += particle.dx * time;
+	    particle.y += particle.dy * time;
+	    (particle.x, particle.y) = super::wrap((particle.x, particle.y));
+	    particle.age < particle.life
+	});
+    });
+}
+
+pub fn draw(ctx: &mut Context, canvas: &mut graphics::Canvas, camera: Camera) {
+    BATCH.with_borrow_mut(|batch| {
+	let batch = batch.get_or_insert_with(|| {
+	    let speck = graphics::Image::from_color(ctx, 1, 1, Some(graphics::Color::WHITE));
+	    graphics::InstanceArray::new(ctx, speck)
+	});
+	PARTICLES.with_borrow(|particles| {
+	    batch.set(particles.iter().map(|particle| {
+		let through = particle.age.as_secs_f32() / particle.life.as_secs_f32();
+		let mix = |start: f32, end: f32| start + (end - start) * through;
+		let size = *(particle.size * camera.scale).value();
+		let (x, y) = camera.near((particle.x, particle.y));
+		graphics::DrawParam::default()
+		    .offset(glam::vec2(0.5, 0.5))
+		    .dest(glam::vec2(
+			*(x * camera.scale).value() - camera.left,
+			*(y * camera.scale).value() - camera.top,
+		    ))
+		    .scale(glam::vec2(size, size))
+		    .color(graphics::Color::new(
+			mix(particle.color.r, particle.fade.r),
+			mix(particle.color.g, particle.fade.g),
+			mix(particle.color.b, particle.fade.b),
+			mix(particle.color.a, particle.fade.a),
+		    ))
+	    }));
+	});
+	canvas.draw(batch, graphics::DrawParam::default());
+    });
+}
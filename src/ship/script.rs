@@ -0,0 +1,367 @@
+// Ships and projectiles written in Rhai, from resources/scripts/<name>.rhai.
+// A script has define() saying what it is built on, and may have update(),
+// collide(other) and damaged(amount), each called with `this` as the actor:
+//
+//   fn define() {
+//       #{ base: "cruiser", spec: #{ maxspeed: 600.0 }, image: "/mods/lancer.png", captain: "Ada" }
+//   }
+//
+//   fn update() {
+//       this.steer = if this.pressed("right") { 1.0 } else if this.pressed("left") { -1.0 } else { 0.0 };
+//       this.throttle = if this.pressed("thrust") { 1.0 } else { 0.0 };
+//       if this.pressed("fire") && this.battery >= 2 && this.cooldown("fire", 0.25) {
+//           this.battery -= 2;
+//           this.summon("lancerbolt", 64.0, 0.0, 2400.0);
+//       }
+//   }
+//
+// In a pack's script, names without a pack are looked for in the same pack,
+// and an image path not starting with / is within the pack.
+//
+// collide may this.hit(damage) what it touched, and returns "kinetic" to
+// bounce; anything else passes through, and a missing collide bounces.
+// Positions are in TSU and angles in radians, but a spec is in turns as in
+// resources/specs.
+use super::*;
+use super::data::SpecFile;
+use crate::assets::SharedImage;
+use crate::stats::Captain;
+use std::collections::HashMap;
+use std::cell::{Cell, RefCell};
+use std::io::Read;
+use rhai::{Engine, AST, Scope, Dynamic, Map, CallFnOptions, FLOAT, INT};
+
+thread_local! {
+    static ENGINE: Engine = engine();
+    static SCRIPTS: RefCell<HashMap<String, Rc<Script>>> = RefCell::new(HashMap::new());
+    // the generations of assets and specs the scripts were compiled in, as either may change under them
+    static COMPILED: Cell<(u64, u64)> = const { Cell::new((0, 0)) };
+}
+
+struct Script {
+    name: String,
+    ast: AST,
+    specs: &'static ActorSpec,
+    captain: Option<String>,
+}
+
+impl Script {
+    fn load(ctx: &mut Context, name: &str) -> Result<Rc<Script>, String> {
+	let generation = (crate::assets::generation(), data::generation());
+	if COMPILED.replace(generation) != generation {
+	    SCRIPTS.with_borrow_mut(HashMap::clear);
+	}
+	if let Some(script) = SCRIPTS.with_borrow(|scripts| scripts.get(name).cloned()) {
+	    return Ok(script);
+	}
+
+	let src = match name.split_once(':') {
+	    Some((pack, name)) => format!("/{pack}/scripts/{name}.rhai"),
+	    None => format!("/scripts/{name}.rhai"),
+	};
+	let mut text = String::new();
+	ctx.fs.open(&src)
+	    .map_err(|error| error.to_string())?
+	    .read_to_string(&mut text)
+	    .map_err(|error| error.to_string())?;
+	let fail = |error: &dyn std::fmt::Display| format!("{src}: {error}");
+
+	let (ast, definition) = ENGINE.with(|engine| {
+	    let ast = engine.compile(&text).map_err(|error| fail(&error))?;
+	    let definition: Map = engine.call_fn(&mut Scope::new(), &ast, "define", ()).map_err(|error| fail(&error))?;
+	    Ok::<_, String>((ast, definition))
+	})?;
+
+	let text = |key: &str| definition.get(key).map(|value| value.clone().into_string().map_err(|_| fail(&format!("{key} must be a string"))));
+	let base = text("base").ok_or_else(|| fail(&"define() has no base"))??;
+	let mut image = text("image").ok_or_else(|| fail(&"define() has no image"))??;
+	if !image.starts_with('/') {
+	    image = match name.split_once(':') {
+		Some((pack, _)) => format!("/{pack}/{image}"),
+		None => format!("/{image}"),
+	    };
+	}
+	let captain = text("captain").transpose()?;
+	// by way of toml, which lets whole numbers stand in for fractional ones
+	let mut spec: toml::Value = match definition.get("spec") {
+	    Some(spec) => rhai::serde::from_dynamic(spec).map_err(|error| fail(&error))?,
+	    None => toml::Value::Table(Default::default()),
+	};
+	// the image goes in the spec, as with the built-in ones
+	if let Some(table) = spec.as_table_mut() {
+	    table.insert("image".to_owned(), toml::Value::String(image));
+	}
+	let overrides: SpecFile = spec.try_into().map_err(|error| fail(&error))?;
+	let specs = data::derive(&base, overrides).map_err(|error| fail(&error))?;
+	if matches!(specs.objecttype, ObjectType::Ship) && captain.is_none() {
+	    return Err(fail(&"a ship needs a captain"));
+	}
+
+	let script = Rc::new(Script {
+	    name: name.to_owned(),
+	    ast,
+	    specs,
+	    captain,
+	});
+	SCRIPTS.with_borrow_mut(|scripts| scripts.insert(name.to_owned(), script.clone()));
+	Ok(script)
+    }
+
+    fn defines(&self, function: &str) -> bool {
+	self.ast.iter_functions().any(|defined| defined.name == function)
+    }
+
+    fn actor(self: &Rc<Self>, ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), time: Instant, affiliation: Option<NonZeroU8>, generator: ActorGeneratorEnum) -> (Actor, Option<graphics::Image>) {
+	let image = SharedImage::load(ctx, self.specs.image);
+	let native = ActorNative::new(image, position, self.specs, affiliation);
+	let captain = self.captain.as_ref().map(|name| Captain::new(ctx, self.specs, name));
+	let display = captain.as_ref().map(Captain::extract_display);
+	let translator = Scripted {
+	    script: self.clone(),
+	    born: time,
+	    state: Map::new(),
+	    cooldowns: HashMap::new(),
+	    broken: false,
+	    captain: captain.map(Box::new),
+	};
+	(Actor::new(native, generator, ActorTranslatorEnum::Other(Box::new(translator))), display)
+    }
+}
+
+// a ship made by a script, for when no built-in one has the name
+pub fn gen(ctx: &mut Context, name: &str, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> Result<(Actor, graphics::Image), String> {
+    let script = Script::load(ctx, name)?;
+    if !matches!(script.specs.objecttype, ObjectType::Ship) {
+	return Err(format!("{name} is not a ship"));
+    }
+    let (actor, display) = script.actor(ctx, position, time, Some(affiliation), generator);
+    Ok((actor, display.expect("ships have captains")))
+}
+
+pub struct Scripted {
+    script: Rc<Script>,
+    born: Instant,
+    state: Map,
+    cooldowns: HashMap<String, FLOAT>,
+    broken: bool,// reported once, then left alone
+    captain: Option<Box<Captain<15>>>,
+}
+
+impl Scripted {
+    fn call(&mut self, this: &mut ScriptActor, function: &str, args: impl rhai::FuncArgs) -> Option<Dynamic> {
+	if self.broken || !self.script.defines(function) {
+	    return None;
+	}
+	this.state = std::mem::take(&mut self.state);
+	this.cooldowns = std::mem::take(&mut self.cooldowns);
+	let mut bound = Dynamic::from(this.clone());
+	let result = ENGINE.with(|engine| engine.call_fn_with_options::<Dynamic>(
+	    CallFnOptions::new().eval_ast(false).bind_this_ptr(&mut bound),
+	    &mut Scope::new(),
+	    &self.script.ast,
+	    function,
+	    args,
+	));
+	*this = bound.cast();
+	self.state = std::mem::take(&mut this.state);
+	self.cooldowns = std::mem::take(&mut this.cooldowns);
+	match result {
+	    Ok(value) => Some(value),
+	    Err(error) => {
+		eprintln!("/scripts/{}.rhai: {}(): {}", self.script.name, function, error);
+		self.broken = true;
+		None
+	    },
+	}
+    }
+}
+
+impl ActorTranslator for Scripted {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let mut this = ScriptActor::new(native, native, input, time.duration_since(self.born));
+	self.call(&mut this, "update", ());
+	this.apply(native);
+
+	let mut request = Request::new(this.steer as f32, this.throttle as f32);
+	request.heading = this.heading as f32;
+	for summon in this.summons {
+	    let name = match self.script.name.split_once(':') {
+		Some((pack, _)) if !summon.name.contains(':') => format!("{pack}:{}", summon.name),
+		_ => summon.name,
+	    };
+	    let script = match Script::load(ctx, &name) {
+		Ok(script) => script,
+		Err(error) => {
+		    eprintln!("{error}");
+		    continue;
+		},
+	    };
+	    let direction = native.direction + summon.angle;
+	    let unit = (direction.cos(), direction.sin());
+	    let position = ((native.x + summon.offset * unit.0, native.y + summon.offset * unit.1), direction);
+	    let (actor, _) = script.actor(ctx, position, time, native.affiliation, super::NoControl.into());
+	    request.summon.push(actor.with_velocity((summon.speed * unit.0, summon.speed * unit.1)));
+	}
+	Ok(request)
+    }
+
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if !self.script.defines("collide") {
+	    return CollisionType::Kinetic;
+	}
+	let age = crate::clock::now().duration_since(self.born);
+	let mut this = ScriptActor::new(native, native, Input::default(), age);
+	let theirs = ScriptActor::new(&other.native, native, Input::default(), Duration::ZERO);
+	let result = self.call(&mut this, "collide", (theirs,));
+	this.apply(native);
+
+	if this.strike > 0 {
+	    other.damage(this.strike.min(u8::MAX as INT) as u8);
+	}
+	match result.and_then(|result| result.into_string().ok()) {
+	    Some(kind) if kind == "kinetic" => CollisionType::Kinetic,
+	    Some(_) => CollisionType::Silent,
+	    None => CollisionType::Kinetic,
+	}
+    }
+
+    fn damaged(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, damage: u8) -> u8 {
+	let age = crate::clock::now().duration_since(self.born);
+	let mut this = ScriptActor::new(native, native, Input::default(), age);
+	let result = self.call(&mut this, "damaged", (damage as INT,)).and_then(|result| result.as_int().ok());
+	this.apply(native);
+	result.map_or(damage, |damage| damage.clamp(0, u8::MAX as INT) as u8)
+    }
+
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	match &mut self.captain {
+	    Some(captain) => captain.update_input(ctx, input, time, native),
+	    None => Ok(()),
+	}
+    }
+}
+
+#[derive(Clone)]
+struct Summon {
+    name: String,
+    offset: units::TrueSpaceUnit<f32>,
+    angle: f32,
+    speed: units::TrueSpaceUnitPerSecond<f32>,
+}
+
+// what a script sees of an actor, copied back once it returns
+#[derive(Clone)]
+struct ScriptActor {
+    x: FLOAT,
+    y: FLOAT,
+    dx: FLOAT,
+    dy: FLOAT,
+    direction: FLOAT,
+    crew: INT,
+    battery: INT,
+    maxcrew: INT,
+    maxbattery: INT,
+    dead: bool,
+    friendly: bool,
+    ship: bool,
+    age: FLOAT,
+    input: Input,
+    steer: FLOAT,
+    throttle: FLOAT,
+    heading: FLOAT,
+    state: Map,
+    cooldowns: HashMap<String, FLOAT>,
+    summons: Vec<Summon>,
+    strike: INT,// dealt to what collide was called with
+}
+
+impl ScriptActor {
+    fn new(native: &ActorNative, viewer: &ActorNative, input: Input, age: Duration) -> Self {
+	// where the viewer sees it, across the edges of the arena
+	let (x, y) = viewer.near((native.x, native.y));
+	ScriptActor {
+	    x: x.value_unsafe as FLOAT,
+	    y: y.value_unsafe as FLOAT,
+	    dx: native.dx.value_unsafe as FLOAT,
+	    dy: native.dy.value_unsafe as FLOAT,
+	    direction: native.direction as FLOAT,
+	    crew: native.crew as INT,
+	    battery: native.battery as INT,
+	    maxcrew: native.specs.maxcrew as INT,
+	    maxbattery: native.specs.maxbattery as INT,
+	    dead: native.dead,
+	    friendly: native.affiliation == viewer.affiliation,
+	    ship: matches!(native.specs.objecttype, ObjectType::Ship),
+	    age: age.as_secs_f64() as FLOAT,
+	    input,
+	    steer: 0.0,
+	    throttle: 0.0,
+	    heading: 0.0,
+	    state: Map::new(),
+	    cooldowns: HashMap::new(),
+	    summons: Vec::new(),
+	    strike: 0,
+	}
+    }
+
+    fn apply(&self, native: &mut ActorNative) {
+	native.x = self.x as f32 * units::TSU;
+	native.y = self.y as f32 * units::TSU;
+	native.dx = self.dx as f32 * units::TSUpS;
+	native.dy = self.dy as f32 * units::TSUpS;
+	native.direction = self.direction as f32;
+	native.crew = self.crew.clamp(0, native.specs.maxcrew as INT) as u8;
+	native.battery = self.battery.clamp(0, native.specs.maxbattery as INT) as u8;
+	native.dead |= self.dead || native.crew == 0;
+    }
+}
+
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.register_type_with_name::<ScriptActor>("Actor")
+	.register_get_set("x", |this: &mut ScriptActor| this.x, |this: &mut ScriptActor, value: FLOAT| this.x = value)
+	.register_get_set("y", |this: &mut ScriptActor| this.y, |this: &mut ScriptActor, value: FLOAT| this.y = value)
+	.register_get_set("dx", |this: &mut ScriptActor| this.dx, |this: &mut ScriptActor, value: FLOAT| this.dx = value)
+	.register_get_set("dy", |this: &mut ScriptActor| this.dy, |this: &mut ScriptActor, value: FLOAT| this.dy = value)
+	.register_get_set("direction", |this: &mut ScriptActor| this.direction, |this: &mut ScriptActor, value: FLOAT| this.direction = value)
+	.register_get_set("crew", |this: &mut ScriptActor| this.crew, |this: &mut ScriptActor, value: INT| this.crew = value)
+	.register_get_set("battery", |this: &mut ScriptActor| this.battery, |this: &mut ScriptActor, value: INT| this.battery = value)
+	.register_get_set("dead", |this: &mut ScriptActor| this.dead, |this: &mut ScriptActor, value: bool| this.dead = value)
+	.register_get_set("steer", |this: &mut ScriptActor| this.steer, |this: &mut ScriptActor, value: FLOAT| this.steer = value.clamp(-1.0, 1.0))
+	.register_get_set("throttle", |this: &mut ScriptActor| this.throttle, |this: &mut ScriptActor, value: FLOAT| this.throttle = value.clamp(0.0, 1.0))
+	.register_get_set("heading", |this: &mut ScriptActor| this.heading, |this: &mut ScriptActor, value: FLOAT| this.heading = value)
+	.register_get_set("state", |this: &mut ScriptActor| this.state.clone(), |this: &mut ScriptActor, value: Map| this.state = value)
+	.register_get("maxcrew", |this: &mut ScriptActor| this.maxcrew)
+	.register_get("maxbattery", |this: &mut ScriptActor| this.maxbattery)
+	.register_get("friendly", |this: &mut ScriptActor| this.friendly)
+	.register_get("ship", |this: &mut ScriptActor| this.ship)
+	.register_get("age", |this: &mut ScriptActor| this.age)
+	.register_fn("pressed", |this: &mut ScriptActor, key: &str| match key {
+	    "left" => this.input.is(Input::LEFT),
+	    "right" => this.input.is(Input::RIGHT),
+	    "thrust" => this.input.is(Input::THRUST),
+	    "fire" => this.input.is(Input::FIRE),
+	    "secondary" => this.input.is(Input::SECONDARY),
+	    _ => false,
+	})
+	// true at most once per period for each key, like FireRate
+	.register_fn("cooldown", |this: &mut ScriptActor, key: &str, period: FLOAT| {
+	    let ready = this.cooldowns.get(key).is_none_or(|last| this.age - last >= period);
+	    if ready {
+		this.cooldowns.insert(key.to_owned(), this.age);
+	    }
+	    ready
+	})
+	.register_fn("summon", |this: &mut ScriptActor, name: &str, offset: FLOAT, angle: FLOAT, speed: FLOAT| {
+	    this.summons.push(Summon {
+		name: name.to_owned(),
+		offset: offset as f32 * units::TSU,
+		angle: angle as f32,
+		speed: speed as f32 * units::TSUpS,
+	    });
+	})
+	.register_fn("hit", |this: &mut ScriptActor, amount: INT| {
+	    this.strike += amount.max(0);
+	});
+    engine
+}
@@ -1,31 +1,32 @@
 use std::f32::consts::TAU;
 use super::*;
 use crate::stats::Captain;
+use std::rc::Weak;
+use std::cell::{Cell, RefCell};
+use crate::assets::SharedImage;
 use ggez::{Context, GameResult};
 use ggez::graphics;
 use std::time::{Instant, Duration};
 use std::num::NonZeroU8;
 
 pub struct Cruiser {
-    missileimage: graphics::Image,
+    missileimage: SharedImage,
     firerate: FireRate,
     captain: Box<Captain<15>>,
 }
 
 impl Cruiser {
     pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
-	const FIRERATE: Duration = Duration::new(0, 416_666_667);
-
-	let image = graphics::Image::from_path(ctx, "/ships/cruiser/main.png").expect("missing image");
+	let image = SharedImage::load(ctx, data::resolve(&CRUISER).image);
 	let native = ActorNative::new(image, position, &CRUISER, Some(affiliation));
 	
-	let missileimage = graphics::Image::from_path(ctx, "/ships/cruiser/missile.png").expect("missing image");
+	let missileimage = SharedImage::load(ctx, data::resolve(&CRUISERMISSILE).image);
 	let captain = Captain::new(ctx, &CRUISER, "Kirk");
 	let display = captain.extract_display();
 	
 	let translator = Self {
 	    missileimage,
-	    firerate: FireRate::new(FIRERATE),
+	    firerate: FireRate::new(),
 	    captain: Box::new(captain),
 	};
 
@@ -46,14 +47,15 @@ impl ActorTranslator for Cruiser {
 	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
 
 	let mut summon = Vec::new();
-	if input.is(Input::FIRE) && native.battery >= CruiserMissile::CHARGECOST && self.firerate.try_fire(time) {
-	    native.battery -= CruiserMissile::CHARGECOST;
+	let primary = &data::resolve(&CRUISERMISSILE).weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
 	    let unit = (native.direction.cos(), native.direction.sin());
-	    let dx = CruiserMissile::STARTSPEED * unit.0;
-	    let dy = CruiserMissile::STARTSPEED * unit.1;
+	    let dx = primary.speed * unit.0;
+	    let dy = primary.speed * unit.1;
 	    let native = ActorNative::new(
 		self.missileimage.clone(),
-		((native.x + CruiserMissile::STARTOFFSET * unit.0, native.y + CruiserMissile::STARTOFFSET * unit.1), native.direction),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
 		&CRUISERMISSILE,
 		native.affiliation
 	    );
@@ -62,13 +64,13 @@ impl ActorTranslator for Cruiser {
 		    native,
 		    super::NoControl.into(),
 		    CruiserMissile {
-			ttl: Timer::new(time, CruiserMissile::TTL),
+			ttl: Timer::new(time, primary.ttl),
 		    }.into(),
 		).with_velocity((dx, dy))
 	    );
 	}
 
-	Ok(Request{steer, throttle, summon})
+	Ok(Request{steer, throttle, heading: 0.0, impulse: (0.0 * units::TSUpS, 0.0 * units::TSUpS), shift: (0.0 * units::TSU, 0.0 * units::TSU), summon})
     }
     
     fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
@@ -80,6 +82,13 @@ impl ActorTranslator for Cruiser {
     }
 }
 
+pub const CRUISERFLAME: AnimationSpec = AnimationSpec {
+    src: "/ships/cruiser/flame.ani",
+    frametime: Duration::new(0, 50_000_000),
+    playback: Playback::Loop,
+    cues: &[],
+};
+
 pub static CRUISER: ActorSpec = ActorSpec {
     maxspeed: units::TrueSpaceUnitPerSecond::new(576.0),
     acceleration: units::TrueSpaceUnitPerSecond2::new(345.6),
@@ -94,26 +103,35 @@ pub static CRUISER: ActorSpec = ActorSpec {
     },
     objecttype: ObjectType::Ship,
     takesdamage: true,
+    forcedthrust: false,
     maxcrew: 18,
     maxbattery: 18,
     chargetime: Duration::new(0, 375_000_000),
     chargevalue: 1,
     species: "Human",
     captainsrc: Some("/ships/cruiser/cruiser-cap.ani"),
+    image: "/ships/cruiser/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/cruiser/cruiser-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	thrust: Some(CRUISERFLAME),
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
 };
 
 pub struct CruiserMissile {
     ttl: Timer,
 }
 
-impl CruiserMissile {
-    const TTL: Duration = Duration::new(2, 500_000_000);
-    const STARTSPEED: units::TrueSpaceUnitPerSecond<f32> = units::TrueSpaceUnitPerSecond::new(960.0);
-    const STARTOFFSET: units::TrueSpaceUnit<f32> = units::TrueSpaceUnit::new(128.0);
-    const DAMAGE: u8 = 4;
-    const CHARGECOST: u8 = 9;
-}
-
 impl ActorTranslator for CruiserMissile {
     fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
 	if self.ttl.done(time) {
@@ -121,57 +139,11 @@ impl ActorTranslator for CruiserMissile {
 	    return Ok(Request::new(0.0, 0.0));
 	}
 	
-	let mut target: Option<(&Actor, units::TrueSpaceUnit2<f32>)> = None;
-	for ship in others {
-	    if let Some(affiliation) = ship.native.affiliation {
-		if native.affiliation != Some(affiliation) {
-		    // Try to chase this one, if better
-		    match target {
-			None => {
-			    let distx = native.x - ship.native.x;
-			    let disty = native.y - ship.native.y;
-			    let distsq = distx*distx + disty*disty;
-			    target = Some((&ship, distsq));
-			},
-			Some((_prev, prevdistsq)) => {
-			    let distx = native.x - ship.native.x;
-			    let disty = native.y - ship.native.y;
-			    let distsq = distx*distx + disty*disty;
-			    if distsq < prevdistsq {
-				target = Some((&ship, distsq));
-			    }
-			}
-		    }
-		}
-	    }
-	}
-	
-	let mut steering: f32 = 0.0;
-	if let Some((ship, distsq)) = target {
-	    let distx = native.x - ship.native.x;
-	    let disty = native.y - ship.native.y;
-
-	    let offset = native.direction.sin() * distx - native.direction.cos() * disty;
-
-	    const FULLTURN: f32 = 0.05;// less than this will have proportionally less
-	    let offsetsq = offset * offset;
-	    let factorsq = offsetsq / distsq;
-	    if *factorsq > FULLTURN*FULLTURN {
-		steering = 1.0;
-	    } else {
-		steering = *factorsq.sqrt() / FULLTURN;
-	    }
-
-	    if offset < 0.0 * units::TSU {
-		steering = -steering;
-	    }
-	}
-	
-	Ok(Request::new(steering, 1.0))
+	Ok(Request::new(homing(native, others), 1.0))
     }
     
     fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
-	other.damage(CruiserMissile::DAMAGE);
+	other.damage(native.specs.weapon.damage);
 	native.dead = true;
 	CollisionType::Silent
     }
@@ -181,6 +153,54 @@ impl ActorTranslator for CruiserMissile {
     }
 }
 
+// steering that chases the nearest actor of another side
+fn homing(native: &ActorNative, others: Chain<Iter<Actor>, Iter<Actor>>) -> f32 {
+    let mut target: Option<(&Actor, units::TrueSpaceUnit2<f32>)> = None;
+    for ship in others {
+	if let Some(affiliation) = ship.native.affiliation {
+	    if native.affiliation != Some(affiliation) {
+		// Try to chase this one, if better
+		match target {
+		    None => {
+			let (distx, disty) = wrap((native.x - ship.native.x, native.y - ship.native.y));
+			let distsq = distx*distx + disty*disty;
+			target = Some((ship, distsq));
+		    },
+		    Some((_prev, prevdistsq)) => {
+			let (distx, disty) = wrap((native.x - ship.native.x, native.y - ship.native.y));
+			let distsq = distx*distx + disty*disty;
+			if distsq < prevdistsq {
+			    target = Some((ship, distsq));
+			}
+		    }
+		}
+	    }
+	}
+    }
+
+    let mut steering: f32 = 0.0;
+    if let Some((ship, distsq)) = target {
+	let (distx, disty) = wrap((native.x - ship.native.x, native.y - ship.native.y));
+
+	let offset = native.direction.sin() * distx - native.direction.cos() * disty;
+
+	const FULLTURN: f32 = 0.05;// less than this will have proportionally less
+	let offsetsq = offset * offset;
+	let factorsq = offsetsq / distsq;
+	if *factorsq > FULLTURN*FULLTURN {
+	    steering = 1.0;
+	} else {
+	    steering = *factorsq.sqrt() / FULLTURN;
+	}
+
+	if offset < 0.0 * units::TSU {
+	    steering = -steering;
+	}
+    }
+
+    steering
+}
+
 pub static CRUISERMISSILE: ActorSpec = ActorSpec {
     maxspeed: units::TrueSpaceUnitPerSecond::new(1920.0),
     acceleration: units::TrueSpaceUnitPerSecond2::new(30720.0),
@@ -195,12 +215,28 @@ pub static CRUISERMISSILE: ActorSpec = ActorSpec {
     },
     objecttype: ObjectType::Projectile,
     takesdamage: true,
+    forcedthrust: false,
     maxcrew: 4,
     maxbattery: 0,
     chargetime: Duration::new(0, 0),
     chargevalue: 0,
     species: "",
     captainsrc: None,
+    image: "/ships/cruiser/missile.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters {
+	trail: Some(IONTRAIL),
+	..Emitters::NONE
+    },
+    weapon: Weapon {
+	damage: 4,
+	cost: 9,
+	cooldown: Duration::new(0, 416_666_667),
+	ttl: Duration::new(2, 500_000_000),
+	speed: units::TrueSpaceUnitPerSecond::new(960.0),
+	offset: units::TrueSpaceUnit::new(128.0),
+    },
 };
 
 pub struct Avenger {
@@ -209,7 +245,7 @@ pub struct Avenger {
 
 impl Avenger {
     pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
-	let image = graphics::Image::from_path(ctx, "/ships/avenger/main.png").expect("missing image");
+	let image = SharedImage::load(ctx, data::resolve(&AVENGER).image);
 	let native = ActorNative::new(image, position, &AVENGER, Some(affiliation));
 	
 	let captain = Captain::new(ctx, &AVENGER, "Gorgon");
@@ -260,10 +296,3264 @@ pub static AVENGER: ActorSpec = ActorSpec {
     },
     objecttype: ObjectType::Ship,
     takesdamage: true,
+    forcedthrust: false,
     maxcrew: 22,
     maxbattery: 16,
     chargetime: Duration::new(0, 208_333_333),
     chargevalue: 4,
     species: "Ilwrath",
     captainsrc: Some("/ships/avenger/avenger-cap.ani"),
+    image: "/ships/avenger/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/avenger/avenger-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+pub struct Mauler {
+    cannonimage: SharedImage,
+    firerate: FireRate,
+    furnacerate: FireRate,
+    captain: Box<Captain<15>>,
+}
+
+impl Mauler {
+    const MAXRECOIL: units::TrueSpaceUnitPerSecond<f32> = units::TrueSpaceUnitPerSecond::new(2688.0);
+    const RECOIL: units::TrueSpaceUnitPerSecond<f32> = units::TrueSpaceUnitPerSecond::new(672.0);
+    const FURNACEVALUE: u8 = 16;// battery gained for each crew member
+    const FURNACERATE: Duration = Duration::new(1, 250_000_000);
+    
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&MAULER).image);
+	let native = ActorNative::new(image, position, &MAULER, Some(affiliation));
+	
+	let cannonimage = SharedImage::load(ctx, data::resolve(&MAULERCANNON).image);
+	let captain = Captain::new(ctx, &MAULER, "Kaprunk");
+	let display = captain.extract_display();
+	
+	let translator = Self {
+	    cannonimage,
+	    firerate: FireRate::new(),
+	    furnacerate: FireRate::new(),
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Mauler {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	let primary = &data::resolve(&MAULERCANNON).weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let dx = primary.speed * unit.0;
+	    let dy = primary.speed * unit.1;
+	    let cannon = ActorNative::new(
+		self.cannonimage.clone(),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
+		&MAULERCANNON,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    cannon,
+		    super::NoControl.into(),
+		    Shot::new(time, primary.ttl, primary.damage).into(),
+		).with_velocity((dx, dy))
+	    );
+
+	    // the recoil cannot build up without limit
+	    if native.dx*native.dx + native.dy*native.dy < Self::MAXRECOIL*Self::MAXRECOIL {
+		request.impulse = (-Self::RECOIL * unit.0, -Self::RECOIL * unit.1);
+	    }
+	}
+
+	// never sacrifice the last crew member
+	if input.is(Input::SECONDARY) && native.crew > 1 && native.battery < native.specs.maxbattery && self.furnacerate.try_fire(time, Self::FURNACERATE) {
+	    native.crew -= 1;
+	    native.battery = native.battery.saturating_add(Self::FURNACEVALUE).min(native.specs.maxbattery);
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+}
+
+pub static MAULER: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(480.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(576.0),
+    mass: units::Ton::new(5.0),
+    turnspeed: units::RadianPerSecond::new(0.3 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(4.8 * TAU),
+    inertia: units::TrueSpaceUnit2::new(2400.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(120.0),
+	radius: units::TrueSpaceUnit::new(20.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 14,
+    maxbattery: 32,
+    chargetime: Duration::new(2, 125_000_000),
+    chargevalue: 1,
+    species: "Druuge",
+    captainsrc: Some("/ships/mauler/mauler-cap.ani"),
+    image: "/ships/mauler/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/mauler/mauler-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+// flies straight until it runs out of time or hits something
+pub struct Shot {
+    ttl: Timer,
+    damage: u8,
+    sided: bool,// passes through its own side
+}
+
+impl Shot {
+    fn new(time: Instant, ttl: Duration, damage: u8) -> Self {
+	Shot {
+	    ttl: Timer::new(time, ttl),
+	    damage,
+	    sided: false,
+	}
+    }
+
+    // for shots that start out overlapping their own ship or its escorts
+    fn sided(time: Instant, ttl: Duration, damage: u8) -> Self {
+	Shot {
+	    sided: true,
+	    ..Self::new(time, ttl, damage)
+	}
+    }
+}
+
+impl ActorTranslator for Shot {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if self.ttl.done(time) {
+	    native.dead = true;
+	}
+	Ok(Request::new(0.0, 0.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if !(self.sided && other.native.affiliation == native.affiliation) {
+	    other.damage(self.damage);
+	    native.dead = true;
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub static MAULERCANNON: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(2880.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(10.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/mauler/cannon.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 6,
+	cost: 4,
+	cooldown: Duration::new(0, 416_666_667),
+	ttl: Duration::new(0, 833_333_333),
+	speed: units::TrueSpaceUnitPerSecond::new(2880.0),
+	offset: units::TrueSpaceUnit::new(96.0),
+    },
+};
+
+pub struct Stinger {
+    sprayimage: SharedImage,
+    tongueimage: SharedImage,
+    firerate: FireRate,
+    tonguerate: FireRate,
+    tether: Tether,
+    captain: Box<Captain<15>>,
+}
+
+impl Stinger {
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&STINGER).image);
+	let native = ActorNative::new(image, position, &STINGER, Some(affiliation));
+        
+	let sprayimage = SharedImage::load(ctx, data::resolve(&STINGERSPRAY).image);
+	let tongueimage = SharedImage::load(ctx, data::resolve(&STINGERTONGUE).image);
+	let captain = Captain::new(ctx, &STINGER, "FipPat");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    sprayimage,
+	    tongueimage,
+	    firerate: FireRate::new(),
+	    tonguerate: FireRate::new(),
+	    tether: Default::default(),
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Stinger {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	let primary = &data::resolve(&STINGERSPRAY).weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let dx = primary.speed * unit.0;
+	    let dy = primary.speed * unit.1;
+	    let spray = ActorNative::new(
+		self.sprayimage.clone(),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
+		&STINGERSPRAY,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    spray,
+		    super::NoControl.into(),
+		    Shot::new(time, primary.ttl, primary.damage).into(),
+		).with_velocity((dx, dy))
+	    );
+	}
+
+	let secondary = &data::resolve(&STINGERTONGUE).weapon;
+	if input.is(Input::SECONDARY) && native.battery >= secondary.cost && self.tonguerate.try_fire(time, secondary.cooldown) {
+	    native.battery -= secondary.cost;
+	    // held on the ship for its short life
+	    let tongue = ActorNative::new(
+		self.tongueimage.clone(),
+		((native.x, native.y), native.direction),
+		&STINGERTONGUE,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    tongue,
+		    super::NoControl.into(),
+		    Tongue {
+			ttl: Timer::new(time, secondary.ttl),
+			damage: secondary.damage,
+			parent: self.tether.follow(),
+			struck: Vec::new(),
+		    }.into(),
+		)
+	    );
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+
+    fn moved(&mut self, native: &ActorNative, input: Input) {
+	self.tether.set(native, input);
+    }
+}
+
+pub static STINGER: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(960.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(5760.0),
+    mass: units::Ton::new(5.0),
+    turnspeed: units::RadianPerSecond::new(0.75 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(12.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(1536.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(48.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 10,
+    maxbattery: 10,
+    chargetime: Duration::new(0, 208_333_333),
+    chargevalue: 1,
+    species: "Zoq-Fot-Pik",
+    captainsrc: Some("/ships/stinger/stinger-cap.ani"),
+    image: "/ships/stinger/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/stinger/stinger-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+pub static STINGERSPRAY: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(1248.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(6.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/stinger/spray.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 1,
+	cost: 1,
+	cooldown: Duration::new(0, 41_666_667),
+	ttl: Duration::new(0, 416_666_667),
+	speed: units::TrueSpaceUnitPerSecond::new(1248.0),
+	offset: units::TrueSpaceUnit::new(60.0),
+    },
+};
+
+// strikes everything in front of the ship except its own side, each once
+pub struct Tongue {
+    ttl: Timer,
+    damage: u8,
+    parent: Weak<Cell<Anchor>>,
+    struck: Vec<u64>,// serials
+}
+
+impl ActorTranslator for Tongue {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let Some(parent) = self.parent.upgrade().filter(|_| !self.ttl.done(time)) else {
+	    native.dead = true;
+	    return Ok(Request::new(0.0, 0.0));
+	};
+	// placed where the ship has just moved to, so it stays still itself
+	let anchor = parent.get();
+	native.x = anchor.x;
+	native.y = anchor.y;
+	native.direction = anchor.direction;
+	native.dx = 0.0 * units::TSUpS;
+	native.dy = 0.0 * units::TSUpS;
+	Ok(Request::new(0.0, 0.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if other.native.affiliation != native.affiliation && !self.struck.contains(&other.native.serial) {
+	    self.struck.push(other.native.serial);
+	    other.damage(self.damage);
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub static STINGERTONGUE: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(960.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Arc {
+	radius: units::TrueSpaceUnit::new(150.0),
+	halfangle: TAU / 16.0,
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: false,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/stinger/tongue.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 12,
+	cost: 7,
+	cooldown: Duration::new(0, 291_666_667),
+	ttl: Duration::new(0, 125_000_000),
+	..Weapon::NONE
+    },
 };
+
+pub struct Avatar {
+    laserimage: SharedImage,
+    zapsatimage: SharedImage,
+    zaplaserimage: SharedImage,
+    firerate: FireRate,
+    tractorrate: FireRate,
+    tractoring: bool,
+    deployed: bool,
+    tether: Tether,
+    captain: Box<Captain<15>>,
+}
+
+impl Avatar {
+    const TRACTORCOST: u8 = 1;
+    const TRACTORRATE: Duration = Duration::new(0, 83_333_333);
+    const TRACTORRANGE: units::TrueSpaceUnit<f32> = units::TrueSpaceUnit::new(1200.0);
+    const TRACTORPULL: units::TrueSpaceUnitPerSecond2<f32> = units::TrueSpaceUnitPerSecond2::new(1440.0);
+    const ZAPSATS: usize = 3;
+    
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&AVATAR).image);
+	let native = ActorNative::new(image, position, &AVATAR, Some(affiliation));
+        
+	let laserimage = SharedImage::load(ctx, data::resolve(&AVATARLASER).image);
+	let zapsatimage = SharedImage::load(ctx, data::resolve(&ZAPSAT).image);
+	let zaplaserimage = SharedImage::load(ctx, data::resolve(&ZAPSATLASER).image);
+	let captain = Captain::new(ctx, &AVATAR, "Mnzgk");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    laserimage,
+	    zapsatimage,
+	    zaplaserimage,
+	    firerate: FireRate::new(),
+	    tractorrate: FireRate::new(),
+	    tractoring: false,
+	    deployed: false,
+	    tether: Default::default(),
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Avatar {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	if !self.deployed {
+	    self.deployed = true;
+	    for index in 0..Self::ZAPSATS {
+		let phase = index as f32 * TAU / Self::ZAPSATS as f32;
+		let zapsat = ActorNative::new(
+		    self.zapsatimage.clone(),
+		    ((native.x + ZapSat::ORBIT * phase.cos(), native.y + ZapSat::ORBIT * phase.sin()), phase),
+		    &ZAPSAT,
+		    native.affiliation
+		);
+		request.summon.push(
+		    Actor::new(
+			zapsat,
+			super::NoControl.into(),
+			ZapSat {
+			    parent: self.tether.follow(),
+			    phase,
+			    laserimage: self.zaplaserimage.clone(),
+			    firerate: FireRate::new(),
+			}.into(),
+		    )
+		);
+	    }
+	}
+
+	let primary = &data::resolve(&AVATARLASER).weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let laser = ActorNative::new(
+		self.laserimage.clone(),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
+		&AVATARLASER,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    laser,
+		    super::NoControl.into(),
+		    Shot::sided(time, primary.ttl, primary.damage).into(),
+		).with_velocity((native.dx, native.dy))
+	    );
+	}
+
+	// the beam holds as long as there is battery to pay for it
+	if input.is(Input::SECONDARY) {
+	    if self.tractorrate.try_fire(time, Self::TRACTORRATE) {
+		self.tractoring = native.battery >= Self::TRACTORCOST;
+		if self.tractoring {
+		    native.battery -= Self::TRACTORCOST;
+		}
+	    }
+	} else {
+	    self.tractoring = false;
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+
+    fn moved(&mut self, native: &ActorNative, input: Input) {
+	self.tether.set(native, input);
+    }
+
+    fn affect(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) {
+	if !self.tractoring || !matches!(other.native.specs.objecttype, ObjectType::Ship) || other.native.affiliation == native.affiliation {
+	    return;
+	}
+        
+	let (distx, disty) = wrap((native.x - other.native.x, native.y - other.native.y));
+	let distsq = distx*distx + disty*disty;
+	if distsq < Self::TRACTORRANGE*Self::TRACTORRANGE {
+	    let time = clock::delta().as_secs_f32() * units::S;
+	    let dist = distsq.sqrt();
+	    other.native.dx += Self::TRACTORPULL * time * (distx / dist);
+	    other.native.dy += Self::TRACTORPULL * time * (disty / dist);
+	}
+    }
+}
+
+pub static AVATAR: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(840.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(672.0),
+    mass: units::Ton::new(10.0),
+    turnspeed: units::RadianPerSecond::new(0.375 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(6.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(3600.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(140.0),
+	radius: units::TrueSpaceUnit::new(30.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 42,
+    maxbattery: 42,
+    chargetime: Duration::new(0, 83_333_333),
+    chargevalue: 1,
+    species: "Chmmr",
+    captainsrc: Some("/ships/avatar/avatar-cap.ani"),
+    image: "/ships/avatar/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/avatar/avatar-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+pub static AVATARLASER: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(840.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(600.0),
+	radius: units::TrueSpaceUnit::new(4.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: false,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/avatar/laser.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 2,
+	cost: 2,
+	cooldown: Duration::new(0, 41_666_667),
+	ttl: Duration::new(0, 41_666_667),
+	offset: units::TrueSpaceUnit::new(370.0),// to the center of the beam
+	..Weapon::NONE
+    },
+};
+
+// circles the parent, shooting at anything hostile that comes near
+pub struct ZapSat {
+    parent: Weak<Cell<Anchor>>,
+    phase: f32,
+    laserimage: SharedImage,
+    firerate: FireRate,
+}
+
+impl ZapSat {
+    const ORBIT: units::TrueSpaceUnit<f32> = units::TrueSpaceUnit::new(240.0);
+    const ORBITSPEED: units::RadianPerSecond<f32> = units::RadianPerSecond::new(0.5 * TAU);
+}
+
+impl ActorTranslator for ZapSat {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let Some(parent) = self.parent.upgrade() else {
+	    native.dead = true;
+	    return Ok(Request::new(0.0, 0.0));
+	};
+	let parent = parent.get();
+
+	// choose the velocity that lands exactly on the orbit this frame
+	let delta = clock::delta().as_secs_f32() * units::S;
+	if delta > 0.0 * units::S {
+	    self.phase = (self.phase + *(Self::ORBITSPEED * delta).value()) % TAU;
+	    let targetx = parent.x + Self::ORBIT * self.phase.cos();
+	    let targety = parent.y + Self::ORBIT * self.phase.sin();
+	    let (offsetx, offsety) = wrap((targetx - native.x, targety - native.y));
+	    native.dx = offsetx / delta;
+	    native.dy = offsety / delta;
+	}
+	native.direction = self.phase;
+
+	let mut request = Request::new(0.0, 0.0);
+	let laserspecs = data::resolve(&ZAPSATLASER);
+	let length = laserspecs.hitbox.length();// its reach, as it starts from the satellite
+        
+	let mut target: Option<(&Actor, units::TrueSpaceUnit2<f32>)> = None;
+	for other in others {
+	    if other.native.affiliation.is_none() || other.native.affiliation == native.affiliation {
+		continue;
+	    }
+	    let (distx, disty) = wrap((other.native.x - native.x, other.native.y - native.y));
+	    let distsq = distx*distx + disty*disty;
+	    if distsq < length*length && !matches!(target, Some((_, prevdistsq)) if prevdistsq <= distsq) {
+		target = Some((other, distsq));
+	    }
+	}
+
+	let laser = &laserspecs.weapon;
+	if let Some((other, _)) = target {
+	    if self.firerate.try_fire(time, laser.cooldown) {
+		let (distx, disty) = wrap((other.native.x - native.x, other.native.y - native.y));
+		let direction = disty.value_unsafe.atan2(distx.value_unsafe);
+		let unit = (direction.cos(), direction.sin());
+		let beam = ActorNative::new(
+		    self.laserimage.clone(),
+		    ((native.x + length * 0.5 * unit.0, native.y + length * 0.5 * unit.1), direction),
+		    &ZAPSATLASER,
+		    native.affiliation
+		);
+		request.summon.push(
+		    Actor::new(
+			beam,
+			super::NoControl.into(),
+			Shot::sided(time, laser.ttl, laser.damage).into(),
+		    )
+		);
+	    }
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub static ZAPSAT: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(0.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(400.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(20.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 3,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/avatar/zapsat.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon::NONE,
+};
+
+pub static ZAPSATLASER: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(0.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(400.0),
+	radius: units::TrueSpaceUnit::new(3.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: false,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/avatar/zaplaser.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 1,
+	cooldown: Duration::new(0, 500_000_000),
+	ttl: Duration::new(0, 41_666_667),
+	..Weapon::NONE
+    },
+};
+
+pub struct Penetrator {
+    daggerimage: SharedImage,
+    crewimage: SharedImage,
+    firerate: FireRate,
+    songrate: FireRate,
+    singing: bool,
+    stolen: Vec<(units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>)>,// one for each crew pod to release
+    captain: Box<Captain<15>>,
+}
+
+impl Penetrator {
+    const STARTCREW: u8 = 12;
+    const SONGCOST: u8 = 5;
+    const SONGRATE: Duration = Duration::new(0, 833_333_333);
+    const SONGRANGE: units::TrueSpaceUnit<f32> = units::TrueSpaceUnit::new(960.0);
+    const SONGCREW: u8 = 8;// most crew lured out by a single song
+    
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&PENETRATOR).image);
+	let mut native = ActorNative::new(image, position, &PENETRATOR, Some(affiliation));
+	native.crew = Self::STARTCREW;// room to spare for stolen crew
+        
+	let daggerimage = SharedImage::load(ctx, data::resolve(&PENETRATORDAGGER).image);
+	let crewimage = SharedImage::load(ctx, data::resolve(&CREWPOD).image);
+	let captain = Captain::new(ctx, &PENETRATOR, "Teela");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    daggerimage,
+	    crewimage,
+	    firerate: FireRate::new(),
+	    songrate: FireRate::new(),
+	    singing: false,
+	    stolen: Vec::new(),
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Penetrator {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	// from the song last frame
+	let released = &data::resolve(&CREWPOD).weapon;
+	for (x, y) in self.stolen.drain(..) {
+	    let angle = rand::random::<f32>() * TAU;
+	    let unit = (angle.cos(), angle.sin());
+	    let pod = ActorNative::new(
+		self.crewimage.clone(),
+		((x + released.offset * unit.0, y + released.offset * unit.1), angle),
+		&CREWPOD,
+		None
+	    );
+	    request.summon.push(
+		Actor::new(
+		    pod,
+		    super::NoControl.into(),
+		    CrewPod {
+			ttl: Timer::new(time, released.ttl),
+		    }.into(),
+		).with_velocity((released.speed * unit.0, released.speed * unit.1))
+	    );
+	}
+
+	let primary = &data::resolve(&PENETRATORDAGGER).weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let dx = primary.speed * unit.0;
+	    let dy = primary.speed * unit.1;
+	    let dagger = ActorNative::new(
+		self.daggerimage.clone(),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
+		&PENETRATORDAGGER,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    dagger,
+		    super::NoControl.into(),
+		    Shot::new(time, primary.ttl, primary.damage).into(),
+		).with_velocity((dx, dy))
+	    );
+	}
+
+	// lasts for the coming round of affect
+	self.singing = input.is(Input::SECONDARY) && native.battery >= Self::SONGCOST && self.songrate.try_fire(time, Self::SONGRATE);
+	if self.singing {
+	    native.battery -= Self::SONGCOST;
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+
+    fn affect(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) {
+	if !self.singing || !matches!(other.native.specs.objecttype, ObjectType::Ship) || other.native.affiliation == native.affiliation {
+	    return;
+	}
+        
+	let (distx, disty) = wrap((native.x - other.native.x, native.y - other.native.y));
+	if distx*distx + disty*disty < Self::SONGRANGE*Self::SONGRANGE {
+	    // the captain never leaves
+	    let lured = Self::SONGCREW.min(other.native.crew.saturating_sub(1));
+	    other.native.crew -= lured;
+	    for _ in 0..lured {
+		self.stolen.push((other.native.x, other.native.y));
+	    }
+	}
+    }
+}
+
+pub static PENETRATOR: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(864.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(2592.0),
+    mass: units::Ton::new(2.0),
+    turnspeed: units::RadianPerSecond::new(0.75 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(12.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(1152.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(96.0),
+	radius: units::TrueSpaceUnit::new(16.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 42,
+    maxbattery: 16,
+    chargetime: Duration::new(0, 291_666_667),
+    chargevalue: 1,
+    species: "Syreen",
+    captainsrc: Some("/ships/penetrator/penetrator-cap.ani"),
+    image: "/ships/penetrator/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/penetrator/penetrator-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+pub static PENETRATORDAGGER: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(2880.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(24.0),
+	radius: units::TrueSpaceUnit::new(4.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/penetrator/dagger.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 2,
+	cost: 1,
+	cooldown: Duration::new(0, 333_333_333),
+	ttl: Duration::new(0, 416_666_667),
+	speed: units::TrueSpaceUnitPerSecond::new(2880.0),
+	offset: units::TrueSpaceUnit::new(80.0),
+    },
+};
+
+// drifts free until a ship with room picks it up
+pub struct CrewPod {
+    ttl: Timer,
+}
+
+impl ActorTranslator for CrewPod {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if self.ttl.done(time) {
+	    native.dead = true;
+	}
+	Ok(Request::new(0.0, 0.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if !native.dead && other.heal(1) != 0 {
+	    native.dead = true;
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub static CREWPOD: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(120.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(0.1),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(8.0),
+    },
+    objecttype: ObjectType::Pickup,
+    takesdamage: false,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/crew.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	ttl: Duration::new(8, 0),
+	speed: units::TrueSpaceUnitPerSecond::new(120.0),
+	offset: units::TrueSpaceUnit::new(120.0),// from where the crew was lured out
+	..Weapon::NONE
+    },
+};
+
+pub struct Podship {
+    plasmoidimage: SharedImage,
+    firerate: FireRate,
+    captain: Box<Captain<15>>,
+}
+
+impl Podship {
+    const REGROWCREW: u8 = 4;// costs a full battery
+    
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&PODSHIP).image);
+	let native = ActorNative::new(image, position, &PODSHIP, Some(affiliation));
+        
+	let plasmoidimage = SharedImage::load(ctx, data::resolve(&PLASMOID).image);
+	let captain = Captain::new(ctx, &PODSHIP, "Glorx");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    plasmoidimage,
+	    firerate: FireRate::new(),
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Podship {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	let primary = &data::resolve(&PLASMOID).weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let dx = primary.speed * unit.0;
+	    let dy = primary.speed * unit.1;
+	    let plasmoid = ActorNative::new(
+		self.plasmoidimage.clone(),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
+		&PLASMOID,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    plasmoid,
+		    super::NoControl.into(),
+		    Plasmoid {
+			ttl: Timer::new(time, primary.ttl),
+			damage: primary.damage,
+		    }.into(),
+		).with_velocity((dx, dy))
+	    );
+	}
+
+	if input.is(Input::SECONDARY) && native.battery == native.specs.maxbattery && native.crew < native.specs.maxcrew {
+	    native.battery = 0;
+	    native.crew = native.crew.saturating_add(Self::REGROWCREW).min(native.specs.maxcrew);
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+}
+
+pub static PODSHIP: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(648.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(740.6),
+    mass: units::Ton::new(7.0),
+    turnspeed: units::RadianPerSecond::new(0.214 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(3.43 * TAU),
+    inertia: units::TrueSpaceUnit2::new(3200.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(64.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 20,
+    maxbattery: 40,
+    chargetime: Duration::new(0, 208_333_333),
+    chargevalue: 1,
+    species: "Mycon",
+    captainsrc: Some("/ships/podship/podship-cap.ani"),
+    image: "/ships/podship/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/podship/podship-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+// homes in, but burns out as it goes
+pub struct Plasmoid {
+    ttl: Timer,
+    damage: u8,
+}
+
+impl ActorTranslator for Plasmoid {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if self.ttl.done(time) {
+	    native.dead = true;
+	    return Ok(Request::new(0.0, 0.0));
+	}
+
+	// rounded up, so it is never harmless while it lasts
+	let weapon = &native.specs.weapon;
+	let fraction = self.ttl.remaining(time).as_secs_f32() / weapon.ttl.as_secs_f32();
+	self.damage = (weapon.damage as f32 * fraction).ceil() as u8;
+        
+	Ok(Request::new(homing(native, others), 1.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if other.native.affiliation != native.affiliation {
+	    other.damage(self.damage);
+	    native.dead = true;
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub static PLASMOID: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(720.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(2880.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.25 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(4.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(400.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(28.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 10,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/podship/plasmoid.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 10,// at launch, burning out over its ttl
+	cost: 20,
+	cooldown: Duration::new(0, 250_000_000),
+	ttl: Duration::new(3, 0),
+	speed: units::TrueSpaceUnitPerSecond::new(720.0),
+	offset: units::TrueSpaceUnit::new(112.0),
+    },
+};
+
+pub struct Guardian {
+    bubbleimage: SharedImage,
+    firerate: FireRate,
+    drainrate: FireRate,
+    blazing: bool,
+    captain: Box<Captain<15>>,
+}
+
+impl Guardian {
+    const DRAINRATE: Duration = Duration::new(0, 125_000_000);// a unit of battery while blazing
+    
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&GUARDIAN).image);
+	let native = ActorNative::new(image, position, &GUARDIAN, Some(affiliation));
+        
+	let bubbleimage = SharedImage::load(ctx, data::resolve(&BUBBLE).image);
+	let captain = Captain::new(ctx, &GUARDIAN, "BOO-2");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    bubbleimage,
+	    firerate: FireRate::new(),
+	    drainrate: FireRate::new(),
+	    blazing: false,
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Guardian {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	if self.blazing {
+	    if self.drainrate.try_fire(time, Self::DRAINRATE) {
+		native.battery = native.battery.saturating_sub(1);
+		if native.battery == 0 {
+		    self.blazing = false;
+		    native.set_specs(&GUARDIAN);
+		}
+	    }
+	    // a comet cannot hold back, nor fire
+	    return Ok(Request::new(steer, 1.0));
+	}
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	let primary = &data::resolve(&BUBBLE).weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let dx = primary.speed * unit.0;
+	    let dy = primary.speed * unit.1;
+	    let bubble = ActorNative::new(
+		self.bubbleimage.clone(),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
+		&BUBBLE,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    bubble,
+		    super::NoControl.into(),
+		    Bubble {
+			ttl: Timer::new(time, primary.ttl),
+		    }.into(),
+		).with_velocity((dx, dy))
+	    );
+	}
+
+	// paid to transform, draining continues after
+	let secondary = &data::resolve(&BLAZER).weapon;
+	if input.is(Input::SECONDARY) && native.battery >= secondary.cost {
+	    native.battery -= secondary.cost;
+	    self.blazing = true;
+	    native.set_specs(&BLAZER);
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if !self.blazing {
+	    return CollisionType::Kinetic;
+	}
+
+	if other.native.affiliation != native.affiliation {
+	    other.damage(native.specs.weapon.damage);
+	}
+	// burns straight through anything lighter than a ship
+	if matches!(other.native.specs.objecttype, ObjectType::Projectile) {
+	    CollisionType::Silent
+	} else {
+	    CollisionType::Kinetic
+	}
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+}
+
+pub static GUARDIAN: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(576.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(345.6),
+    mass: units::Ton::new(6.0),
+    turnspeed: units::RadianPerSecond::new(0.3 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(4.8 * TAU),
+    inertia: units::TrueSpaceUnit2::new(2304.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(100.0),
+	radius: units::TrueSpaceUnit::new(28.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 20,
+    maxbattery: 24,
+    chargetime: Duration::new(0, 375_000_000),
+    chargevalue: 1,
+    species: "Androsynth",
+    captainsrc: Some("/ships/guardian/guardian-cap.ani"),
+    image: "/ships/guardian/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/guardian/guardian-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+// the Guardian in comet form, with no recharge
+pub static BLAZER: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(1440.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(17280.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.75 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(12.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(576.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(40.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 20,
+    maxbattery: 24,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "Androsynth",
+    captainsrc: Some("/ships/guardian/guardian-cap.ani"),
+    image: "/ships/guardian/blazer.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/guardian/blazer-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon {
+	damage: 3,// on ramming
+	cost: 2,// to transform
+	..Weapon::NONE
+    },
+};
+
+// drifts toward the enemy, but never in a straight line
+pub struct Bubble {
+    ttl: Timer,
+}
+
+impl Bubble {
+    const WANDER: f32 = 1.5;// strength of the random steering
+}
+
+impl ActorTranslator for Bubble {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if self.ttl.done(time) {
+	    native.dead = true;
+	    return Ok(Request::new(0.0, 0.0));
+	}
+
+	let wander = (rand::random::<f32>() * 2.0 - 1.0) * Self::WANDER;
+	let steer = (homing(native, others) + wander).clamp(-1.0, 1.0);
+	Ok(Request::new(steer, 1.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if other.native.affiliation != native.affiliation {
+	    other.damage(native.specs.weapon.damage);
+	    native.dead = true;
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub static BUBBLE: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(480.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(1920.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.5 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(4.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(200.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(12.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/guardian/bubble.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 2,
+	cost: 3,
+	cooldown: Duration::new(0, 41_666_667),
+	ttl: Duration::new(8, 333_333_333),
+	speed: units::TrueSpaceUnitPerSecond::new(480.0),
+	offset: units::TrueSpaceUnit::new(88.0),
+    },
+};
+
+pub struct Jugger {
+    lanceimage: SharedImage,
+    firerate: FireRate,
+    shieldrate: FireRate,
+    shield: Timer,
+    shielded: bool,
+    captain: Box<Captain<15>>,
+}
+
+impl Jugger {
+    const LANCES: usize = 6;
+    const LANCESPACING: units::TrueSpaceUnit<f32> = units::TrueSpaceUnit::new(16.0);
+    
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&JUGGER).image);
+	let native = ActorNative::new(image, position, &JUGGER, Some(affiliation));
+        
+	let lanceimage = SharedImage::load(ctx, data::resolve(&JUGGERLANCE).image);
+	let captain = Captain::new(ctx, &JUGGER, "Zrrp");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    lanceimage,
+	    firerate: FireRate::new(),
+	    shieldrate: FireRate::new(),
+	    shield: Default::default(),
+	    shielded: false,
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Jugger {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	// the lances are free, battery only comes from the shield
+	let primary = &data::resolve(&JUGGERLANCE).weapon;
+	if input.is(Input::FIRE) && self.firerate.try_fire(time, primary.cooldown) {
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let dx = primary.speed * unit.0;
+	    let dy = primary.speed * unit.1;
+	    for index in 0..Self::LANCES {
+		let lateral = (index as f32 - (Self::LANCES - 1) as f32 * 0.5) * Self::LANCESPACING;
+		let lance = ActorNative::new(
+		    self.lanceimage.clone(),
+		    ((native.x + primary.offset * unit.0 - lateral * unit.1, native.y + primary.offset * unit.1 + lateral * unit.0), native.direction),
+		    &JUGGERLANCE,
+		    native.affiliation
+		);
+		request.summon.push(
+		    Actor::new(
+			lance,
+			super::NoControl.into(),
+			Shot::sided(time, primary.ttl, primary.damage).into(),
+		    ).with_velocity((dx, dy))
+		);
+	    }
+	}
+
+	// the shield is held for its ttl
+	let secondary = &data::resolve(&JUGGERSHIELD).weapon;
+	if input.is(Input::SECONDARY) && native.battery >= secondary.cost && self.shieldrate.try_fire(time, secondary.cooldown) {
+	    native.battery -= secondary.cost;
+	    self.shield = Timer::new(time, secondary.ttl);
+	}
+
+	let shielded = !self.shield.done(time);
+	if shielded != self.shielded {
+	    self.shielded = shielded;
+	    native.set_specs(if shielded {&JUGGERSHIELD} else {&JUGGER});
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+
+    fn damaged(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, damage: u8) -> u8 {
+	if !self.shielded {
+	    return damage;
+	}
+	native.battery = native.battery.saturating_add(damage).min(native.specs.maxbattery);
+	0
+    }
+}
+
+pub static JUGGER: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(864.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(493.7),
+    mass: units::Ton::new(8.0),
+    turnspeed: units::RadianPerSecond::new(0.75 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(12.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(2880.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(120.0),
+	radius: units::TrueSpaceUnit::new(30.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 20,
+    maxbattery: 20,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "Utwig",
+    captainsrc: Some("/ships/jugger/jugger-cap.ani"),
+    image: "/ships/jugger/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/jugger/jugger-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+// the shield has no facings, so the shield image shows instead
+pub static JUGGERSHIELD: ActorSpec = ActorSpec {
+    image: "/ships/jugger/shield.png",
+    sprites: None,
+    weapon: Weapon {
+	cost: 1,
+	cooldown: Duration::new(0, 541_666_667),
+	ttl: Duration::new(0, 500_000_000),// that the shield holds
+	..Weapon::NONE
+    },
+    ..JUGGER
+};
+
+pub static JUGGERLANCE: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(2304.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(32.0),
+	radius: units::TrueSpaceUnit::new(3.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/jugger/lance.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 1,
+	cooldown: Duration::new(0, 333_333_333),
+	ttl: Duration::new(0, 416_666_667),
+	speed: units::TrueSpaceUnitPerSecond::new(2304.0),
+	offset: units::TrueSpaceUnit::new(96.0),
+	..Weapon::NONE
+    },
+};
+
+pub struct Torch {
+    hornimage: SharedImage,
+    flameimage: SharedImage,
+    firerate: FireRate,
+    burnrate: FireRate,
+    captain: Box<Captain<15>>,
+}
+
+impl Torch {
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&TORCH).image);
+	let native = ActorNative::new(image, position, &TORCH, Some(affiliation));
+        
+	let hornimage = SharedImage::load(ctx, data::resolve(&TORCHHORN).image);
+	let flameimage = SharedImage::load(ctx, data::resolve(&TORCHFLAME).image);
+	let captain = Captain::new(ctx, &TORCH, "Dthunk");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    hornimage,
+	    flameimage,
+	    firerate: FireRate::new(),
+	    burnrate: FireRate::new(),
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Torch {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+	let unit = (native.direction.cos(), native.direction.sin());
+
+	let primary = &data::resolve(&TORCHHORN).weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    let dx = primary.speed * unit.0;
+	    let dy = primary.speed * unit.1;
+	    let horn = ActorNative::new(
+		self.hornimage.clone(),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
+		&TORCHHORN,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    horn,
+		    super::NoControl.into(),
+		    Shot::sided(time, primary.ttl, primary.damage).into(),
+		).with_velocity((dx, dy))
+	    );
+	}
+
+	// each patch of flame is paid for as it is dropped, behind the engines
+	let secondary = &data::resolve(&TORCHFLAME).weapon;
+	if input.is(Input::SECONDARY) && (native.battery >= secondary.cost || !self.burnrate.ready(time)) {
+	    native.set_specs(&TORCHBURN);
+	    request.throttle = 1.0;
+	    if self.burnrate.try_fire(time, secondary.cooldown) {
+		native.battery -= secondary.cost;
+		let flame = ActorNative::new(
+		    self.flameimage.clone(),
+		    ((native.x + secondary.offset * unit.0, native.y + secondary.offset * unit.1), native.direction),
+		    &TORCHFLAME,
+		    native.affiliation
+		);
+		request.summon.push(
+		    Actor::new(
+			flame,
+			super::NoControl.into(),
+			Flame {
+			    ttl: Timer::new(time, secondary.ttl),
+			    damage: secondary.damage,
+			    scorched: Vec::new(),
+			}.into(),
+		    )
+		);
+	    }
+	} else {
+	    native.set_specs(&TORCH);
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+}
+
+pub static TORCH: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(672.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(4032.0),
+    mass: units::Ton::new(7.0),
+    turnspeed: units::RadianPerSecond::new(0.75 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(12.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(2016.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(104.0),
+	radius: units::TrueSpaceUnit::new(24.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 8,
+    maxbattery: 24,
+    chargetime: Duration::new(0, 291_666_667),
+    chargevalue: 1,
+    species: "Thraddash",
+    captainsrc: Some("/ships/torch/torch-cap.ani"),
+    image: "/ships/torch/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/torch/torch-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+// the Torch with the afterburner lit
+pub static TORCHBURN: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(1728.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(12096.0),
+    ..TORCH
+};
+
+// a form, its ship, and how the form is made from the two
+pub type Form = (&'static ActorSpec, &'static ActorSpec, fn(&ActorSpec, &ActorSpec) -> ActorSpec);
+
+// Forms that differ from their ship in only a few fields. Those are all a form
+// takes from its own spec; the rest follows the ship as it resolves, so
+// tuning the Jugger or Torch tunes its other form too.
+pub static FORMS: &[Form] = &[
+    (&JUGGERSHIELD, &JUGGER, |shield, jugger| ActorSpec {
+	image: shield.image,
+	sprites: shield.sprites,
+	weapon: shield.weapon,
+	..jugger.clone()
+    }),
+    (&TORCHBURN, &TORCH, |burn, torch| ActorSpec {
+	maxspeed: burn.maxspeed,
+	acceleration: burn.acceleration,
+	..torch.clone()
+    }),
+];
+
+pub static TORCHHORN: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(2880.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(10.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/torch/horn.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 1,
+	cost: 2,
+	cooldown: Duration::new(0, 541_666_667),
+	ttl: Duration::new(0, 625_000_000),
+	speed: units::TrueSpaceUnitPerSecond::new(2880.0),
+	offset: units::TrueSpaceUnit::new(72.0),
+    },
+};
+
+// burns whatever of another side passes through, again and again until it goes out
+pub struct Flame {
+    ttl: Timer,
+    damage: u8,
+    scorched: Vec<(u64, Instant)>,// serials, and when each can be burnt again
+}
+
+impl Flame {
+    const COOLDOWN: Duration = Duration::new(0, 500_000_000);
+}
+
+impl ActorTranslator for Flame {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if self.ttl.done(time) {
+	    native.dead = true;
+	}
+	self.scorched.retain(|(_, until)| *until > time);
+	Ok(Request::new(0.0, 0.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if other.native.affiliation != native.affiliation && !self.scorched.iter().any(|(serial, _)| *serial == other.native.serial) {
+	    self.scorched.push((other.native.serial, clock::now() + Self::COOLDOWN));
+	    other.damage(self.damage);
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub static TORCHFLAME: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(0.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(24.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: false,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/torch/flame.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 1,
+	cost: 1,
+	cooldown: Duration::new(0, 83_333_333),
+	ttl: Duration::new(2, 0),
+	offset: units::TrueSpaceUnit::new(-80.0),// behind the engines
+	..Weapon::NONE
+    },
+};
+
+pub struct Drone {
+    coneimage: SharedImage,
+    scorched: Rc<RefCell<Vec<(u64, Instant)>>>,// shared by its cones, so overlapping ones burn once a tick
+    firerate: FireRate,
+    ziprate: FireRate,
+    captain: Box<Captain<15>>,
+}
+
+impl Drone {
+    const ZIPCOST: u8 = 1;
+    const ZIPRATE: Duration = Duration::new(0, 125_000_000);
+    const ZIPDISTANCE: units::TrueSpaceUnit<f32> = units::TrueSpaceUnit::new(160.0);
+    
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&DRONE).image);
+	let native = ActorNative::new(image, position, &DRONE, Some(affiliation));
+        
+	let coneimage = SharedImage::load(ctx, data::resolve(&DRONECONE).image);
+	let captain = Captain::new(ctx, &DRONE, "Julg'ka");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    coneimage,
+	    scorched: Default::default(),
+	    firerate: FireRate::new(),
+	    ziprate: FireRate::new(),
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Drone {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	// the cone costs nothing, but only reaches a short way
+	let primary = &data::resolve(&DRONECONE).weapon;
+	if input.is(Input::FIRE) && self.firerate.try_fire(time, primary.cooldown) {
+	    let cone = ActorNative::new(
+		self.coneimage.clone(),
+		((native.x, native.y), native.direction),
+		&DRONECONE,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    cone,
+		    super::NoControl.into(),
+		    Cone {
+			ttl: Timer::new(time, primary.ttl),
+			damage: primary.damage,
+			scorched: self.scorched.clone(),
+		    }.into(),
+		).with_velocity((native.dx, native.dy))
+	    );
+	}
+
+	if input.is(Input::SECONDARY) && native.battery >= Self::ZIPCOST && self.ziprate.try_fire(time, Self::ZIPRATE) {
+	    native.battery -= Self::ZIPCOST;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    request.shift = (-Self::ZIPDISTANCE * unit.0, -Self::ZIPDISTANCE * unit.1);
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+}
+
+pub static DRONE: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(432.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(864.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.3 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(4.8 * TAU),
+    inertia: units::TrueSpaceUnit2::new(1152.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(44.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 10,
+    maxbattery: 30,
+    chargetime: Duration::new(6, 250_000_000),
+    chargevalue: 30,
+    species: "Umgah",
+    captainsrc: Some("/ships/drone/drone-cap.ani"),
+    image: "/ships/drone/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/drone/drone-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+// strikes everything in front of the ship when it first appears
+// burns whatever is within it each tick, however often it is fired
+pub struct Cone {
+    ttl: Timer,
+    damage: u8,
+    scorched: Rc<RefCell<Vec<(u64, Instant)>>>,// serials, and when each can be burnt again
+}
+
+impl Cone {
+    const TICK: Duration = Duration::new(0, 125_000_000);
+}
+
+impl ActorTranslator for Cone {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if self.ttl.done(time) {
+	    native.dead = true;
+	}
+	self.scorched.borrow_mut().retain(|(_, until)| *until > time);
+	Ok(Request::new(0.0, 0.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	let mut scorched = self.scorched.borrow_mut();
+	if other.native.affiliation != native.affiliation && !scorched.iter().any(|(serial, _)| *serial == other.native.serial) {
+	    scorched.push((other.native.serial, clock::now() + Self::TICK));
+	    other.damage(self.damage);
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub static DRONECONE: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(432.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Arc {
+	radius: units::TrueSpaceUnit::new(160.0),
+	halfangle: TAU / 12.0,
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: false,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/drone/cone.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 1,
+	cooldown: Duration::new(0, 125_000_000),// a fresh cone while the trigger is held
+	ttl: Duration::new(0, 125_000_000),
+	..Weapon::NONE
+    },
+};
+
+pub struct Probe {
+    lightningimage: SharedImage,
+    firerate: FireRate,
+    harvesting: bool,
+    reversing: bool,
+    captain: Box<Captain<15>>,
+}
+
+impl Probe {
+    const BOLTS: usize = 6;// segments in one lightning strike
+    const BOLTJITTER: f32 = TAU / 12.0;
+    const HARVESTRANGE: units::TrueSpaceUnit<f32> = units::TrueSpaceUnit::new(320.0);
+    const HARVESTVALUE: u8 = 5;
+    
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&PROBE).image);
+	let native = ActorNative::new(image, position, &PROBE, Some(affiliation));
+        
+	let lightningimage = SharedImage::load(ctx, data::resolve(&PROBELIGHTNING).image);
+	let captain = Captain::new(ctx, &PROBE, "2418-B");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    lightningimage,
+	    firerate: FireRate::new(),
+	    harvesting: false,
+	    reversing: false,
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Probe {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	// the engines never stop, so thrust turns the ship around instead
+	if input.is(Input::THRUST) && !self.reversing {
+	    native.direction = (native.direction + TAU * 0.5) % TAU;
+	}
+	self.reversing = input.is(Input::THRUST);
+	let mut request = Request::new(steer, 1.0);
+
+	let lightning = data::resolve(&PROBELIGHTNING);
+	let primary = &lightning.weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    let mut x = native.x + primary.offset * native.direction.cos();
+	    let mut y = native.y + primary.offset * native.direction.sin();
+	    for _ in 0..Self::BOLTS {
+		let direction = native.direction + (rand::random::<f32>() * 2.0 - 1.0) * Self::BOLTJITTER;
+		// bolts join end to end, so each is as long as its hitbox
+		let halfx = lightning.hitbox.length() * 0.5 * direction.cos();
+		let halfy = lightning.hitbox.length() * 0.5 * direction.sin();
+		let bolt = ActorNative::new(
+		    self.lightningimage.clone(),
+		    ((x + halfx, y + halfy), direction),
+		    &PROBELIGHTNING,
+		    native.affiliation
+		);
+		request.summon.push(
+		    Actor::new(
+			bolt,
+			super::NoControl.into(),
+			Shot::sided(time, primary.ttl, primary.damage).into(),
+		    )
+		);
+		x += halfx * 2.0;
+		y += halfy * 2.0;
+	    }
+	}
+
+	self.harvesting = input.is(Input::SECONDARY);
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+
+    fn affect(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) {
+	if !self.harvesting || other.native.dead || !other.is_asteroid() {
+	    return;
+	}
+        
+	let (distx, disty) = wrap((native.x - other.native.x, native.y - other.native.y));
+	if distx*distx + disty*disty < Self::HARVESTRANGE*Self::HARVESTRANGE {
+	    other.native.dead = true;
+	    native.battery = native.battery.saturating_add(Self::HARVESTVALUE).min(native.specs.maxbattery);
+	}
+    }
+}
+
+pub static PROBE: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(1440.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(5760.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(1.5 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(24.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(1296.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(36.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: true,
+    maxcrew: 12,
+    maxbattery: 20,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "Slylandro",
+    captainsrc: Some("/ships/probe/probe-cap.ani"),
+    image: "/ships/probe/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/probe/probe-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+pub static PROBELIGHTNING: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(0.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(64.0),
+	radius: units::TrueSpaceUnit::new(4.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: false,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/probe/lightning.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 1,
+	cost: 2,
+	cooldown: Duration::new(0, 708_333_333),
+	ttl: Duration::new(0, 83_333_333),
+	offset: units::TrueSpaceUnit::new(56.0),
+	..Weapon::NONE
+    },
+};
+
+pub struct Marauder {
+    bladeimage: SharedImage,
+    flameimage: SharedImage,
+    firerate: FireRate,
+    ringrate: FireRate,
+    tether: Tether,
+    captain: Box<Captain<15>>,
+}
+
+impl Marauder {
+    const BLADESPIN: units::RadianPerSecond<f32> = units::RadianPerSecond::new(2.0 * TAU);
+    const FLAMES: usize = 16;
+    
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&MARAUDER).image);
+	let native = ActorNative::new(image, position, &MARAUDER, Some(affiliation));
+        
+	let bladeimage = SharedImage::load(ctx, data::resolve(&MARAUDERBLADE).image);
+	let flameimage = SharedImage::load(ctx, data::resolve(&MARAUDERFLAME).image);
+	let captain = Captain::new(ctx, &MARAUDER, "Death 11");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    bladeimage,
+	    flameimage,
+	    firerate: FireRate::new(),
+	    ringrate: FireRate::new(),
+	    tether: Default::default(),
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Marauder {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	let primary = &data::resolve(&MARAUDERBLADE).weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let dx = primary.speed * unit.0;
+	    let dy = primary.speed * unit.1;
+	    let mut blade = ActorNative::new(
+		self.bladeimage.clone(),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
+		&MARAUDERBLADE,
+		native.affiliation
+	    );
+	    // turns the plain image, for when the whirl cannot be shown
+	    blade.angularvelocity = Self::BLADESPIN;
+	    request.summon.push(
+		Actor::new(
+		    blade,
+		    super::NoControl.into(),
+		    Blade {
+			ttl: Timer::new(time, primary.ttl),
+			parent: self.tether.follow(),
+			hovering: false,
+		    }.into(),
+		).with_velocity((dx, dy))
+	    );
+	}
+
+	let secondary = &data::resolve(&MARAUDERFLAME).weapon;
+	if input.is(Input::SECONDARY) && native.battery >= secondary.cost && self.ringrate.try_fire(time, secondary.cooldown) {
+	    native.battery -= secondary.cost;
+	    for index in 0..Self::FLAMES {
+		let direction = index as f32 * TAU / Self::FLAMES as f32;
+		let unit = (direction.cos(), direction.sin());
+		let flame = ActorNative::new(
+		    self.flameimage.clone(),
+		    ((native.x + secondary.offset * unit.0, native.y + secondary.offset * unit.1), direction),
+		    &MARAUDERFLAME,
+		    native.affiliation
+		);
+		request.summon.push(
+		    Actor::new(
+			flame,
+			super::NoControl.into(),
+			RingFlame {
+			    ttl: Timer::new(time, secondary.ttl),
+			}.into(),
+		    ).with_velocity((native.dx + secondary.speed * unit.0, native.dy + secondary.speed * unit.1))
+		);
+	    }
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+
+    fn moved(&mut self, native: &ActorNative, input: Input) {
+	self.tether.set(native, input);
+    }
+}
+
+pub static MARAUDER: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(720.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(493.7),
+    mass: units::Ton::new(10.0),
+    turnspeed: units::RadianPerSecond::new(0.3 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(4.8 * TAU),
+    inertia: units::TrueSpaceUnit2::new(5184.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(72.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 42,
+    maxbattery: 42,
+    chargetime: Duration::new(0, 208_333_333),
+    chargevalue: 1,
+    species: "Kohr-Ah",
+    captainsrc: Some("/ships/marauder/marauder-cap.ani"),
+    image: "/ships/marauder/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/marauder/marauder-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+// flies while the trigger is held, then hangs where it was let go
+pub struct Blade {
+    ttl: Timer,
+    parent: Weak<Cell<Anchor>>,
+    hovering: bool,
+}
+
+impl ActorTranslator for Blade {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if self.ttl.done(time) {
+	    native.dead = true;
+	    return Ok(Request::new(0.0, 0.0));
+	}
+
+	// once stopped it stays stopped, even if the trigger is pulled again
+	if !self.hovering && !self.parent.upgrade().is_some_and(|parent| parent.get().input.is(Input::FIRE)) {
+	    self.hovering = true;
+	    native.dx = 0.0 * units::TSUpS;
+	    native.dy = 0.0 * units::TSUpS;
+	}
+	Ok(Request::new(0.0, 0.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if other.native.affiliation != native.affiliation {
+	    other.damage(native.specs.weapon.damage);
+	    native.dead = true;
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub const BLADEWHIRL: AnimationSpec = AnimationSpec {
+    src: "/ships/marauder/blade.ani",
+    frametime: Duration::new(0, 40_000_000),
+    playback: Playback::Loop,
+    cues: &[],
+};
+
+pub static MARAUDERBLADE: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(1536.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(256.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(20.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/marauder/blade.png",
+    sprites: None,
+    animations: Animations {
+	body: Some(BLADEWHIRL),
+	..Animations::NONE
+    },
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 4,
+	cost: 6,
+	cooldown: Duration::new(0, 291_666_667),
+	ttl: Duration::new(6, 0),
+	speed: units::TrueSpaceUnitPerSecond::new(1536.0),
+	offset: units::TrueSpaceUnit::new(112.0),
+    },
+};
+
+// part of the F.R.I.E.D. ring, clearing projectiles as it goes
+pub struct RingFlame {
+    ttl: Timer,
+}
+
+impl ActorTranslator for RingFlame {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if self.ttl.done(time) {
+	    native.dead = true;
+	}
+	Ok(Request::new(0.0, 0.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if other.native.affiliation == native.affiliation {
+	    return CollisionType::Silent;
+	}
+	if matches!(other.native.specs.objecttype, ObjectType::Projectile) {
+	    other.native.dead = true;
+	} else {
+	    other.damage(native.specs.weapon.damage);
+	    native.dead = true;
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub static MARAUDERFLAME: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(960.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(24.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: false,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/marauder/flame.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 4,
+	cost: 21,
+	cooldown: Duration::new(0, 416_666_667),
+	ttl: Duration::new(0, 750_000_000),
+	speed: units::TrueSpaceUnitPerSecond::new(960.0),
+	offset: units::TrueSpaceUnit::new(80.0),
+    },
+};
+
+pub struct SupoxBlade {
+    globimage: SharedImage,
+    firerate: FireRate,
+    captain: Box<Captain<15>>,
+}
+
+impl SupoxBlade {
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&BLADE).image);
+	let native = ActorNative::new(image, position, &BLADE, Some(affiliation));
+        
+	let globimage = SharedImage::load(ctx, data::resolve(&BLADEGLOB).image);
+	let captain = Captain::new(ctx, &BLADE, "Trifid");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    globimage,
+	    firerate: FireRate::new(),
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for SupoxBlade {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	// turning and thrust become sideways and backwards thrust
+	if input.is(Input::SECONDARY) {
+	    let forward = -throttle;
+	    request.steer = 0.0;
+	    request.throttle = if forward != 0.0 || steer != 0.0 {1.0} else {0.0};
+	    request.heading = steer.atan2(forward);
+	}
+
+	let primary = &data::resolve(&BLADEGLOB).weapon;
+	if input.is(Input::FIRE) && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let dx = primary.speed * unit.0;
+	    let dy = primary.speed * unit.1;
+	    let glob = ActorNative::new(
+		self.globimage.clone(),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
+		&BLADEGLOB,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    glob,
+		    super::NoControl.into(),
+		    Shot::sided(time, primary.ttl, primary.damage).into(),
+		).with_velocity((dx, dy))
+	    );
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+}
+
+pub static BLADE: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(960.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(4608.0),
+    mass: units::Ton::new(4.0),
+    turnspeed: units::RadianPerSecond::new(0.75 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(12.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(1536.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Line {
+	length: units::TrueSpaceUnit::new(96.0),
+	radius: units::TrueSpaceUnit::new(20.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 12,
+    maxbattery: 16,
+    chargetime: Duration::new(0, 208_333_333),
+    chargevalue: 1,
+    species: "Supox",
+    captainsrc: Some("/ships/blade/blade-cap.ani"),
+    image: "/ships/blade/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/blade/blade-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+pub static BLADEGLOB: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(2880.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.0),
+    turnacceleration: units::RadianPerSecond2::new(0.0),
+    inertia: units::TrueSpaceUnit2::new(100.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(8.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/blade/glob.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	damage: 1,
+	cost: 1,
+	cooldown: Duration::new(0, 125_000_000),
+	ttl: Duration::new(0, 416_666_667),
+	speed: units::TrueSpaceUnitPerSecond::new(2880.0),
+	offset: units::TrueSpaceUnit::new(72.0),
+    },
+};
+
+pub struct Trader {
+    blastimage: SharedImage,
+    pulseimage: SharedImage,
+    firerate: FireRate,
+    pulserate: FireRate,
+    charging: bool,
+    tether: Tether,
+    captain: Box<Captain<15>>,
+}
+
+impl Trader {
+    pub fn gen(ctx: &mut Context, position: ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), _time: Instant, affiliation: NonZeroU8, generator: ActorGeneratorEnum) -> (Actor, graphics::Image) {
+	let image = SharedImage::load(ctx, data::resolve(&TRADER).image);
+	let native = ActorNative::new(image, position, &TRADER, Some(affiliation));
+        
+	let blastimage = SharedImage::load(ctx, data::resolve(&TRADERBLAST[0]).image);
+	let pulseimage = SharedImage::load(ctx, data::resolve(&TRADERPULSE).image);
+	let captain = Captain::new(ctx, &TRADER, "Reddish");
+	let display = captain.extract_display();
+        
+	let translator = Self {
+	    blastimage,
+	    pulseimage,
+	    firerate: FireRate::new(),
+	    pulserate: FireRate::new(),
+	    charging: false,
+	    tether: Default::default(),
+	    captain: Box::new(captain),
+	};
+
+	(Actor::new(native, generator, translator.into()), display)
+    }
+}
+
+impl ActorTranslator for Trader {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	let steer = if input.is(Input::RIGHT) {
+	    1.0
+	} else if input.is(Input::LEFT) {
+	    -1.0
+	} else {
+	    0.0
+	};
+
+	let throttle = if input.is(Input::THRUST) {1.0} else {0.0};
+	let mut request = Request::new(steer, throttle);
+
+	// the blast is paid for once, then grows for free until let go
+	let primary = &data::resolve(&TRADERBLAST[0]).weapon;
+	if !input.is(Input::FIRE) {
+	    self.charging = false;
+	} else if !self.charging && native.battery >= primary.cost && self.firerate.try_fire(time, primary.cooldown) {
+	    native.battery -= primary.cost;
+	    self.charging = true;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let blast = ActorNative::new(
+		self.blastimage.clone(),
+		((native.x + primary.offset * unit.0, native.y + primary.offset * unit.1), native.direction),
+		&TRADERBLAST[0],
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    blast,
+		    super::NoControl.into(),
+		    Blast {
+			level: 0,
+			growth: Timer::new(time, Blast::LEVELTIME),
+			ttl: None,
+			parent: self.tether.follow(),
+		    }.into(),
+		).with_velocity((native.dx, native.dy))
+	    );
+	}
+
+	let secondary = &data::resolve(&TRADERPULSE).weapon;
+	if input.is(Input::SECONDARY) && native.battery >= secondary.cost && self.pulserate.try_fire(time, secondary.cooldown) {
+	    native.battery -= secondary.cost;
+	    let unit = (native.direction.cos(), native.direction.sin());
+	    let dx = secondary.speed * unit.0;
+	    let dy = secondary.speed * unit.1;
+	    let pulse = ActorNative::new(
+		self.pulseimage.clone(),
+		((native.x + secondary.offset * unit.0, native.y + secondary.offset * unit.1), native.direction),
+		&TRADERPULSE,
+		native.affiliation
+	    );
+	    request.summon.push(
+		Actor::new(
+		    pulse,
+		    super::NoControl.into(),
+		    Pulse {
+			ttl: Timer::new(time, secondary.ttl),
+		    }.into(),
+		).with_velocity((dx, dy))
+	    );
+	}
+
+	Ok(request)
+    }
+    
+    fn collide(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _other: &mut Actor) -> CollisionType {
+	CollisionType::Kinetic
+    }
+    
+    fn update_captain(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, ctx: &mut Context, input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	self.captain.update_input(ctx, input, time, native)
+    }
+
+    fn moved(&mut self, native: &ActorNative, input: Input) {
+	self.tether.set(native, input);
+    }
+}
+
+pub static TRADER: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(864.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(691.2),
+    mass: units::Ton::new(7.0),
+    turnspeed: units::RadianPerSecond::new(0.3 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(4.8 * TAU),
+    inertia: units::TrueSpaceUnit2::new(2304.0),
+    gravity: Gravity::ACCELERATE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(48.0),
+    },
+    objecttype: ObjectType::Ship,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 20,
+    maxbattery: 42,
+    chargetime: Duration::new(0, 208_333_333),
+    chargevalue: 1,
+    species: "Melnorme",
+    captainsrc: Some("/ships/trader/trader-cap.ani"),
+    image: "/ships/trader/main.png",
+    sprites: Some(SpriteSheet {
+	src: "/ships/trader/trader-big.ani",
+	blend: false,
+    }),
+    animations: Animations {
+	explosion: SHIPEXPLOSION,
+	..Animations::NONE
+    },
+    particles: Emitters {
+	exhaust: Some(EXHAUST),
+	impact: Some(SPARKS),
+	..Emitters::NONE
+    },
+    weapon: Weapon::NONE,
+};
+
+// held in front of the ship and growing while the trigger is held
+pub struct Blast {
+    level: usize,
+    growth: Timer,
+    ttl: Option<Timer>,// set once launched
+    parent: Weak<Cell<Anchor>>,
+}
+
+impl Blast {
+    const LEVELTIME: Duration = Duration::new(0, 500_000_000);
+}
+
+impl ActorTranslator for Blast {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if let Some(ttl) = &self.ttl {
+	    if ttl.done(time) {
+		native.dead = true;
+	    }
+	    return Ok(Request::new(0.0, 0.0));
+	}
+
+	let Some(parent) = self.parent.upgrade() else {
+	    native.dead = true;
+	    return Ok(Request::new(0.0, 0.0));
+	};
+	let anchor = parent.get();
+	let weapon = &native.specs.weapon;
+	let unit = (anchor.direction.cos(), anchor.direction.sin());
+	native.x = anchor.x + weapon.offset * unit.0;
+	native.y = anchor.y + weapon.offset * unit.1;
+	native.direction = anchor.direction;
+
+	if anchor.input.is(Input::FIRE) {
+	    native.dx = anchor.dx;
+	    native.dy = anchor.dy;
+	    if self.level + 1 < TRADERBLAST.len() && self.growth.done(time) {
+		self.level += 1;
+		self.growth = Timer::new(time, Self::LEVELTIME);
+		native.set_specs(&TRADERBLAST[self.level]);
+	    }
+	} else {
+	    native.dx = anchor.dx + weapon.speed * unit.0;
+	    native.dy = anchor.dy + weapon.speed * unit.1;
+	    self.ttl = Some(Timer::new(time, weapon.ttl));
+	}
+	Ok(Request::new(0.0, 0.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if other.native.affiliation != native.affiliation {
+	    other.damage(native.specs.weapon.damage);
+	    native.dead = true;
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+// one per level of charge
+pub static TRADERBLAST: [ActorSpec; 4] = [
+    ActorSpec {
+	maxspeed: units::TrueSpaceUnitPerSecond::new(5184.0),
+	acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+	mass: units::Ton::new(1.0),
+	turnspeed: units::RadianPerSecond::new(0.0),
+	turnacceleration: units::RadianPerSecond2::new(0.0),
+	inertia: units::TrueSpaceUnit2::new(144.0),
+	gravity: Gravity::NONE,
+	hitbox: Hitbox::Circle {
+	    radius: units::TrueSpaceUnit::new(12.0),
+	},
+	objecttype: ObjectType::Projectile,
+	takesdamage: false,
+	forcedthrust: false,
+	maxcrew: 1,
+	maxbattery: 0,
+	chargetime: Duration::new(0, 0),
+	chargevalue: 0,
+	species: "",
+	captainsrc: None,
+	image: "/ships/trader/blast-1.png",
+	sprites: None,
+	animations: Animations::NONE,
+	particles: Emitters::NONE,
+	weapon: Weapon {
+	    damage: 2,
+	    cost: 5,// for the whole charge, paid as it starts
+	    cooldown: Duration::new(0, 83_333_333),
+	    ttl: Duration::new(0, 416_666_667),
+	    speed: units::TrueSpaceUnitPerSecond::new(4320.0),
+	    offset: units::TrueSpaceUnit::new(96.0),
+	},
+    },
+    ActorSpec {
+	maxspeed: units::TrueSpaceUnitPerSecond::new(5184.0),
+	acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+	mass: units::Ton::new(1.0),
+	turnspeed: units::RadianPerSecond::new(0.0),
+	turnacceleration: units::RadianPerSecond2::new(0.0),
+	inertia: units::TrueSpaceUnit2::new(400.0),
+	gravity: Gravity::NONE,
+	hitbox: Hitbox::Circle {
+	    radius: units::TrueSpaceUnit::new(20.0),
+	},
+	objecttype: ObjectType::Projectile,
+	takesdamage: false,
+	forcedthrust: false,
+	maxcrew: 1,
+	maxbattery: 0,
+	chargetime: Duration::new(0, 0),
+	chargevalue: 0,
+	species: "",
+	captainsrc: None,
+	image: "/ships/trader/blast-2.png",
+	sprites: None,
+	animations: Animations::NONE,
+	particles: Emitters::NONE,
+	weapon: Weapon {
+	    damage: 4,
+	    ttl: Duration::new(0, 416_666_667),
+	    speed: units::TrueSpaceUnitPerSecond::new(4320.0),
+	    offset: units::TrueSpaceUnit::new(96.0),
+	    ..Weapon::NONE
+	},
+    },
+    ActorSpec {
+	maxspeed: units::TrueSpaceUnitPerSecond::new(5184.0),
+	acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+	mass: units::Ton::new(1.0),
+	turnspeed: units::RadianPerSecond::new(0.0),
+	turnacceleration: units::RadianPerSecond2::new(0.0),
+	inertia: units::TrueSpaceUnit2::new(784.0),
+	gravity: Gravity::NONE,
+	hitbox: Hitbox::Circle {
+	    radius: units::TrueSpaceUnit::new(28.0),
+	},
+	objecttype: ObjectType::Projectile,
+	takesdamage: false,
+	forcedthrust: false,
+	maxcrew: 1,
+	maxbattery: 0,
+	chargetime: Duration::new(0, 0),
+	chargevalue: 0,
+	species: "",
+	captainsrc: None,
+	image: "/ships/trader/blast-3.png",
+	sprites: None,
+	animations: Animations::NONE,
+	particles: Emitters::NONE,
+	weapon: Weapon {
+	    damage: 6,
+	    ttl: Duration::new(0, 416_666_667),
+	    speed: units::TrueSpaceUnitPerSecond::new(4320.0),
+	    offset: units::TrueSpaceUnit::new(96.0),
+	    ..Weapon::NONE
+	},
+    },
+    ActorSpec {
+	maxspeed: units::TrueSpaceUnitPerSecond::new(5184.0),
+	acceleration: units::TrueSpaceUnitPerSecond2::new(0.0),
+	mass: units::Ton::new(1.0),
+	turnspeed: units::RadianPerSecond::new(0.0),
+	turnacceleration: units::RadianPerSecond2::new(0.0),
+	inertia: units::TrueSpaceUnit2::new(1296.0),
+	gravity: Gravity::NONE,
+	hitbox: Hitbox::Circle {
+	    radius: units::TrueSpaceUnit::new(36.0),
+	},
+	objecttype: ObjectType::Projectile,
+	takesdamage: false,
+	forcedthrust: false,
+	maxcrew: 1,
+	maxbattery: 0,
+	chargetime: Duration::new(0, 0),
+	chargevalue: 0,
+	species: "",
+	captainsrc: None,
+	image: "/ships/trader/blast-4.png",
+	sprites: None,
+	animations: Animations::NONE,
+	particles: Emitters::NONE,
+	weapon: Weapon {
+	    damage: 8,
+	    ttl: Duration::new(0, 416_666_667),
+	    speed: units::TrueSpaceUnitPerSecond::new(4320.0),
+	    offset: units::TrueSpaceUnit::new(96.0),
+	    ..Weapon::NONE
+	},
+    },
+];
+
+// scrambles the helm of whatever ship it reaches
+pub struct Pulse {
+    ttl: Timer,
+}
+
+impl Pulse {
+    const CONFUSION: Duration = Duration::new(3, 0);
+}
+
+impl ActorTranslator for Pulse {
+    fn update(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, time: Instant, others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult<Request> {
+	if self.ttl.done(time) {
+	    native.dead = true;
+	    return Ok(Request::new(0.0, 0.0));
+	}
+	
+	Ok(Request::new(homing(native, others), 1.0))
+    }
+    
+    fn collide(&mut self, native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, other: &mut Actor) -> CollisionType {
+	if other.native.affiliation != native.affiliation {
+	    if matches!(other.native.specs.objecttype, ObjectType::Ship) {
+		other.confuse(Self::CONFUSION);
+	    }
+	    native.dead = true;
+	}
+	CollisionType::Silent
+    }
+    
+    fn update_captain(&mut self, _native: &mut ActorNative, _generator: &mut ActorGeneratorEnum, _ctx: &mut Context, _input: Input, _time: Instant, _others: Chain<Iter<Actor>, Iter<Actor>>) -> GameResult {
+	Ok(())
+    }
+}
+
+pub static TRADERPULSE: ActorSpec = ActorSpec {
+    maxspeed: units::TrueSpaceUnitPerSecond::new(2880.0),
+    acceleration: units::TrueSpaceUnitPerSecond2::new(2880.0),
+    mass: units::Ton::new(1.0),
+    turnspeed: units::RadianPerSecond::new(0.5 * TAU),
+    turnacceleration: units::RadianPerSecond2::new(8.0 * TAU),
+    inertia: units::TrueSpaceUnit2::new(256.0),
+    gravity: Gravity::NONE,
+    hitbox: Hitbox::Circle {
+	radius: units::TrueSpaceUnit::new(16.0),
+    },
+    objecttype: ObjectType::Projectile,
+    takesdamage: true,
+    forcedthrust: false,
+    maxcrew: 1,
+    maxbattery: 0,
+    chargetime: Duration::new(0, 0),
+    chargevalue: 0,
+    species: "",
+    captainsrc: None,
+    image: "/ships/trader/pulse.png",
+    sprites: None,
+    animations: Animations::NONE,
+    particles: Emitters::NONE,
+    weapon: Weapon {
+	cost: 20,
+	cooldown: Duration::new(0, 875_000_000),
+	ttl: Duration::new(0, 833_333_333),
+	speed: units::TrueSpaceUnitPerSecond::new(2880.0),
+	offset: units::TrueSpaceUnit::new(72.0),
+	..Weapon::NONE
+    },
+};
+
+pub type ShipGen = fn(&mut Context, ((units::TrueSpaceUnit<f32>, units::TrueSpaceUnit<f32>), f32), Instant, NonZeroU8, ActorGeneratorEnum) -> (Actor, graphics::Image);
+
+// selectable from the command line
+pub static SHIPS: &[(&str, ShipGen)] = &[
+    ("cruiser", Cruiser::gen),
+    ("avenger", Avenger::gen),
+    ("mauler", Mauler::gen),
+    ("stinger", Stinger::gen),
+    ("avatar", Avatar::gen),
+    ("penetrator", Penetrator::gen),
+    ("podship", Podship::gen),
+    ("guardian", Guardian::gen),
+    ("jugger", Jugger::gen),
+    ("torch", Torch::gen),
+    ("drone", Drone::gen),
+    ("probe", Probe::gen),
+    ("marauder", Marauder::gen),
+    ("blade", SupoxBlade::gen),
+    ("trader", Trader::gen),
+];
+
+pub fn lookup(name: &str) -> Option<ShipGen> {
+    SHIPS.iter().find(|(shipname, _)| *shipname == name).map(|(_, gen)| *gen)
+}
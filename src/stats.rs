@@ -7,6 +7,8 @@ use std::time::{Instant, Duration};
 pub struct Captain<const N: usize> {
     display: graphics::Image,
     activity: super::Animation<N>,
+    activitysrc: &'static str,
+    generation: u64,// of the assets when activity was loaded
     species: &'static str,
     previnput: Input,
     turntimer: Timer,
@@ -20,8 +22,10 @@ impl<const N: usize> Captain<N> {
     
     pub fn new(ctx: &mut Context, spec: &'static crate::ship::ActorSpec, name: &str) -> Self {
 	ctx.gfx.begin_frame().expect("image init frame");// needed to use canvas
-	let activity = super::Animation::new(ctx, spec.captainsrc.expect("no activity image path provided"));
-	let base = graphics::Image::from_path(ctx, "/ships/captain-base.png").expect("captain base image");
+	let spec = crate::ship::data::resolve(spec);
+	let activitysrc = spec.captainsrc.expect("no activity image path provided");
+	let activity = super::Animation::new(ctx, activitysrc);
+	let base = crate::assets::SharedImage::load(ctx, "/ships/captain-base.png").get();
 	let display = graphics::Image::new_canvas_image(ctx, graphics::ImageFormat::Rgba8UnormSrgb, 256, 476, 1);
 	let mut canvas = graphics::Canvas::from_image(ctx, display.clone(), graphics::Color::from_rgb(82, 82, 82));
 	// TODO: add basic specs
@@ -40,6 +44,8 @@ impl<const N: usize> Captain<N> {
 	Captain {
 	    display,
 	    activity,
+	    activitysrc,
+	    generation: crate::assets::generation(),
 	    species: spec.species,
 	    previnput: Default::default(),
 	    turntimer: Default::default(),
@@ -54,6 +60,14 @@ impl<const N: usize> Captain<N> {
     }
 
     pub fn update_input(&mut self, ctx: &mut Context, new: Input, time: Instant, native: &crate::ship::ActorNative) -> GameResult {
+	if self.generation != crate::assets::generation() {
+	    self.generation = crate::assets::generation();
+	    // a broken reload keeps the old images
+	    if let Ok(activity) = super::Animation::try_new(ctx, self.activitysrc) {
+		self.activity = activity;
+	    }
+	}
+	
 	let mut canvas = graphics::Canvas::from_image(ctx, self.display.clone(), None);
 	
 	if new.is(Input::RIGHT) != self.previnput.is(Input::RIGHT) {